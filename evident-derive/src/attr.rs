@@ -0,0 +1,59 @@
+//! Contains helper functions to parse `#[evident(...)]` attributes.
+
+use syn::{
+    meta::ParseNestedMeta, Attribute, Data, DeriveInput, Field, Fields, GenericArgument,
+    PathArguments, Type,
+};
+
+/// Calls the given function for every nested meta item of all `#[evident(...)]` attributes.
+pub(crate) fn parse_evident_attrs(
+    attrs: &[Attribute],
+    mut parse_fn: impl FnMut(ParseNestedMeta) -> syn::Result<()>,
+) -> syn::Result<()> {
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("evident")) {
+        attr.parse_nested_meta(&mut parse_fn)?;
+    }
+    Ok(())
+}
+
+/// Returns the named fields of the given struct, or an error if the input is no struct with named fields.
+pub(crate) fn named_fields<'a>(
+    input: &'a DeriveInput,
+    derive_name: &str,
+) -> syn::Result<Vec<&'a Field>> {
+    match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => Ok(fields.named.iter().collect()),
+            _ => Err(syn::Error::new_spanned(
+                &input.ident,
+                format!(
+                    "`{}` can only be derived for structs with named fields",
+                    derive_name
+                ),
+            )),
+        },
+        _ => Err(syn::Error::new_spanned(
+            &input.ident,
+            format!("`{}` can only be derived for structs", derive_name),
+        )),
+    }
+}
+
+/// Returns the inner type if the given type is an `Option`.
+pub(crate) fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match args.args.first() {
+            Some(GenericArgument::Type(inner)) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
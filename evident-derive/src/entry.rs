@@ -0,0 +1,153 @@
+//! Contains the expansion of `#[derive(EventEntry)]`.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Field, Ident};
+
+use crate::attr::{named_fields, option_inner, parse_evident_attrs};
+
+/// Roles of annotated entry fields.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Role {
+    Id,
+    Msg,
+    Origin,
+    EntryId,
+}
+
+impl Role {
+    fn attr_name(&self) -> &'static str {
+        match self {
+            Role::Id => "id",
+            Role::Msg => "msg",
+            Role::Origin => "origin",
+            Role::EntryId => "entry_id",
+        }
+    }
+}
+
+pub(crate) fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields = named_fields(&input, "EventEntry")?;
+    let mut roles: Vec<(Role, &Field)> = Vec::new();
+    let mut other_fields: Vec<&Ident> = Vec::new();
+
+    for field in fields {
+        let mut field_role = None;
+        parse_evident_attrs(&field.attrs, |meta| {
+            let role = [Role::Id, Role::Msg, Role::Origin, Role::EntryId]
+                .into_iter()
+                .find(|role| meta.path.is_ident(role.attr_name()))
+                .ok_or_else(|| {
+                    meta.error(
+                        "unsupported attribute, expected `id`, `msg`, `origin`, or `entry_id`",
+                    )
+                })?;
+
+            if field_role.is_some() {
+                return Err(meta.error("only one role may be set per field"));
+            }
+            if roles.iter().any(|(r, _)| *r == role) {
+                return Err(meta.error(format!(
+                    "`{}` is set for more than one field",
+                    role.attr_name()
+                )));
+            }
+            field_role = Some(role);
+            Ok(())
+        })?;
+
+        match field_role {
+            Some(role) => roles.push((role, field)),
+            None => other_fields.push(field.ident.as_ref().expect("named field")),
+        }
+    }
+
+    let field_of = |role: Role| -> syn::Result<&Field> {
+        roles
+            .iter()
+            .find(|(r, _)| *r == role)
+            .map(|(_, field)| *field)
+            .ok_or_else(|| {
+                syn::Error::new_spanned(
+                    name,
+                    format!(
+                        "missing field annotated with `#[evident({})]`",
+                        role.attr_name()
+                    ),
+                )
+            })
+    };
+
+    let id_field = field_of(Role::Id)?;
+    let msg_field = field_of(Role::Msg)?;
+    let origin_field = field_of(Role::Origin)?;
+    let entry_id_field = field_of(Role::EntryId)?;
+
+    let id_ident = &id_field.ident;
+    let msg_ident = &msg_field.ident;
+    let origin_ident = &origin_field.ident;
+    let entry_id_ident = &entry_id_field.ident;
+
+    let id_type = &id_field.ty;
+    let msg_type = option_inner(&msg_field.ty).ok_or_else(|| {
+        syn::Error::new_spanned(
+            &msg_field.ty,
+            "the message field must be of type `Option<M>`",
+        )
+    })?;
+
+    Ok(quote! {
+        impl #impl_generics ::evident::event::entry::EventEntry<#id_type, #msg_type> for #name #ty_generics #where_clause {
+            fn new(
+                event_id: #id_type,
+                msg: ::core::option::Option<impl ::core::convert::Into<#msg_type>>,
+                origin: ::evident::event::origin::Origin,
+            ) -> Self {
+                <Self as ::evident::event::entry::EventEntry<#id_type, #msg_type>>::with_entry_id(
+                    event_id,
+                    ::evident::uuid::Uuid::new_v4(),
+                    msg,
+                    origin,
+                )
+            }
+
+            fn with_entry_id(
+                event_id: #id_type,
+                entry_id: ::evident::uuid::Uuid,
+                msg: ::core::option::Option<impl ::core::convert::Into<#msg_type>>,
+                origin: ::evident::event::origin::Origin,
+            ) -> Self {
+                Self {
+                    #id_ident: event_id,
+                    #msg_ident: msg.map(::core::convert::Into::into),
+                    #origin_ident: origin,
+                    #entry_id_ident: entry_id,
+                    #(#other_fields: ::core::default::Default::default(),)*
+                }
+            }
+
+            fn get_event_id(&self) -> &#id_type {
+                &self.#id_ident
+            }
+
+            fn into_event_id(self) -> #id_type {
+                self.#id_ident
+            }
+
+            fn get_entry_id(&self) -> ::evident::uuid::Uuid {
+                self.#entry_id_ident
+            }
+
+            fn get_msg(&self) -> ::core::option::Option<&#msg_type> {
+                self.#msg_ident.as_ref()
+            }
+
+            fn get_origin(&self) -> &::evident::event::origin::Origin {
+                &self.#origin_ident
+            }
+        }
+    })
+}
@@ -0,0 +1,62 @@
+//! Contains the expansion of `#[derive(Id)]`.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Expr};
+
+use crate::attr::parse_evident_attrs;
+
+pub(crate) fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut start: Option<Expr> = None;
+    let mut stop: Option<Expr> = None;
+
+    parse_evident_attrs(&input.attrs, |meta| {
+        if meta.path.is_ident("start") {
+            start = Some(meta.value()?.parse()?);
+            Ok(())
+        } else if meta.path.is_ident("stop") {
+            stop = Some(meta.value()?.parse()?);
+            Ok(())
+        } else {
+            Err(meta.error("unsupported attribute, expected `start` or `stop`"))
+        }
+    })?;
+
+    let capture_control = match (start, stop) {
+        (Some(start), Some(stop)) => quote! {
+            impl #impl_generics ::evident::publisher::CaptureControl for #name #ty_generics #where_clause {
+                fn start(id: &Self) -> bool {
+                    id == &#start
+                }
+
+                fn start_id() -> Self {
+                    ::core::clone::Clone::clone(&#start)
+                }
+
+                fn stop(id: &Self) -> bool {
+                    id == &#stop
+                }
+
+                fn stop_id() -> Self {
+                    ::core::clone::Clone::clone(&#stop)
+                }
+            }
+        },
+        (None, None) => TokenStream::new(),
+        _ => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "`start` and `stop` must be set together to derive `CaptureControl`",
+            ))
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::evident::event::Id for #name #ty_generics #where_clause {}
+
+        #capture_control
+    })
+}
@@ -0,0 +1,133 @@
+//! Contains the expansion of `#[derive(IntermediaryEvent)]`.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Field, Ident, Type};
+
+use crate::attr::{named_fields, option_inner, parse_evident_attrs};
+
+pub(crate) fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+
+    let mut id_type: Option<Type> = None;
+    let mut msg_type: Option<Type> = None;
+
+    parse_evident_attrs(&input.attrs, |meta| {
+        if meta.path.is_ident("id_type") {
+            id_type = Some(meta.value()?.parse()?);
+            Ok(())
+        } else if meta.path.is_ident("msg_type") {
+            msg_type = Some(meta.value()?.parse()?);
+            Ok(())
+        } else {
+            Err(meta.error("unsupported attribute, expected `id_type` or `msg_type`"))
+        }
+    })?;
+
+    let fields = named_fields(&input, "IntermediaryEvent")?;
+    let mut entry_fields: Vec<&Field> = Vec::new();
+    for field in &fields {
+        let mut is_entry = false;
+        parse_evident_attrs(&field.attrs, |meta| {
+            if meta.path.is_ident("entry") {
+                is_entry = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported attribute, expected `entry`"))
+            }
+        })?;
+
+        if is_entry {
+            entry_fields.push(field);
+        }
+    }
+
+    let entry_field = match (entry_fields.as_slice(), fields.as_slice()) {
+        ([entry_field], _) => *entry_field,
+        ([], [single_field]) => *single_field,
+        ([], _) => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "missing field annotated with `#[evident(entry)]`",
+            ))
+        }
+        (_, _) => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "`#[evident(entry)]` is set for more than one field",
+            ))
+        }
+    };
+    let entry_ident = entry_field.ident.as_ref().expect("named field");
+    let other_fields: Vec<&Ident> = fields
+        .iter()
+        .filter_map(|field| field.ident.as_ref())
+        .filter(|ident| *ident != entry_ident)
+        .collect();
+
+    // Note: The entry must be stored as `Option`, so `take_entry()` returns `None` once the entry was taken.
+    let entry_type = option_inner(&entry_field.ty).ok_or_else(|| {
+        syn::Error::new_spanned(
+            &entry_field.ty,
+            "the entry must be stored as `Option` of the entry type",
+        )
+    })?;
+
+    // Note: Event-ID and message types are generic over all `EventEntry` implementations of the entry type if not set explicitly.
+    let mut generics = input.generics.clone();
+    let (id_type, msg_type) = match (id_type, msg_type) {
+        (Some(id_type), Some(msg_type)) => (quote!(#id_type), quote!(#msg_type)),
+        (None, None) => {
+            generics
+                .params
+                .push(syn::parse_quote!(__EvidentK: ::evident::event::Id));
+            generics
+                .params
+                .push(syn::parse_quote!(__EvidentM: ::evident::event::Msg));
+            generics
+                .make_where_clause()
+                .predicates
+                .push(syn::parse_quote!(
+                    #entry_type: ::evident::event::entry::EventEntry<__EvidentK, __EvidentM>
+                ));
+            (quote!(__EvidentK), quote!(__EvidentM))
+        }
+        _ => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "`id_type` and `msg_type` must be set together",
+            ))
+        }
+    };
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    let create_entry = quote! {
+        <#entry_type as ::evident::event::entry::EventEntry<#id_type, #msg_type>>::new(event_id, msg, origin)
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::evident::event::intermediary::IntermediaryEvent<#id_type, #msg_type, #entry_type> for #name #ty_generics #where_clause {
+            fn new(
+                event_id: #id_type,
+                msg: ::core::option::Option<impl ::core::convert::Into<#msg_type>>,
+                origin: ::evident::event::origin::Origin,
+            ) -> Self {
+                Self {
+                    #entry_ident: ::core::option::Option::Some(#create_entry),
+                    #(#other_fields: ::core::default::Default::default(),)*
+                }
+            }
+
+            fn get_entry(&self) -> &#entry_type {
+                self.#entry_ident
+                    .as_ref()
+                    .expect("Entry of intermediary event was already taken.")
+            }
+
+            fn take_entry(&mut self) -> ::core::option::Option<#entry_type> {
+                self.#entry_ident.take()
+            }
+        }
+    })
+}
@@ -0,0 +1,93 @@
+//! Derive macros for the traits of the [*evident*](https://docs.rs/evident) crate.
+//!
+//! **Note:** Enable the `derive` feature of *evident* instead of depending on this crate directly.
+//! The macros are then available next to their traits (e.g. `evident::event::Id`).
+//!
+//! [req:qa.ux.usage]
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+mod attr;
+mod entry;
+mod id;
+mod intermediary;
+
+/// Derives the `Id` trait.
+///
+/// The `CaptureControl` trait is derived as well, if the IDs to start and stop capturing are set:
+///
+/// ```ignore
+/// #[derive(Debug, Default, Clone, Hash, PartialEq, Eq, evident::event::Id)]
+/// #[evident(start = START_CAPTURING, stop = STOP_CAPTURING)]
+/// pub struct MinId {
+///     pub id: isize,
+/// }
+/// ```
+///
+/// [req:event.id], [req:cap.ctrl]
+#[proc_macro_derive(Id, attributes(evident))]
+pub fn derive_id(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    id::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives the `EventEntry` trait.
+///
+/// The fields holding the event-ID, message, origin, and entry-ID must be annotated.
+/// The message field must be of type `Option<M>`, and the entry-ID field of type `evident::uuid::Uuid`.
+/// All other fields are set using `Default` on creation.
+///
+/// ```ignore
+/// #[derive(Default, Clone, evident::event::entry::EventEntry)]
+/// pub struct MinEventEntry {
+///     #[evident(id)]
+///     event_id: MinId,
+///     #[evident(msg)]
+///     msg: Option<String>,
+///     #[evident(origin)]
+///     origin: evident::event::origin::Origin,
+///     #[evident(entry_id)]
+///     entry_id: evident::uuid::Uuid,
+/// }
+/// ```
+///
+/// **Note:** The given entry-ID is kept for entries created with `with_entry_id()`.
+///
+/// [req:event.entry]
+#[proc_macro_derive(EventEntry, attributes(evident))]
+pub fn derive_event_entry(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    entry::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives the `IntermediaryEvent` trait.
+///
+/// The field holding the event-entry must be annotated, unless it is the only field.
+/// The entry must be stored as `Option` of the entry type, so the event is captured at most once.
+/// Other fields are set using `Default` on creation.
+///
+/// The event-ID and message types are taken from the entry type.
+/// Set them explicitly, if the entry type implements `EventEntry` for more than one combination:
+///
+/// ```ignore
+/// #[derive(evident::event::intermediary::IntermediaryEvent)]
+/// #[evident(id_type = MinId, msg_type = String)]
+/// pub struct MinInterimEvent {
+///     #[evident(entry)]
+///     entry: Option<MinEventEntry>,
+/// }
+/// ```
+///
+/// [req:event.entry]
+#[proc_macro_derive(IntermediaryEvent, attributes(evident))]
+pub fn derive_intermediary_event(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    intermediary::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
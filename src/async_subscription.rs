@@ -0,0 +1,163 @@
+//! Contains the [`AsyncSubscription`] struct that is returned by `subscribe_*_async` functions from an [`EvidentPublisher`](crate::publisher::EvidentPublisher).
+//!
+//! **Note:** Only available with the `async` feature.
+//!
+//! [req:subs]
+
+use std::{
+    hash::Hash,
+    pin::Pin,
+    sync::{atomic::Ordering, Arc},
+    task::{Context, Poll},
+};
+
+use futures::{channel::mpsc::Receiver, Stream, StreamExt};
+
+use crate::{
+    event::{entry::EventEntry, filter::Filter, Event, Id, Msg},
+    publisher::CaptureControl,
+    subscription::{SubscriptionCore, SubscriptionError},
+};
+
+/// Async subscription that is returned when subscribing to events captured by an [`EvidentPublisher`](crate::publisher::EvidentPublisher).
+///
+/// Captured events may be received using [`recv()`](AsyncSubscription::recv), or by using the subscription as [`Stream`].
+///
+/// [req:subs]
+pub struct AsyncSubscription<'p, K, M, T, F>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+    T: EventEntry<K, M>,
+    F: Filter<K, M>,
+{
+    /// The connection to the [`EvidentPublisher`](crate::publisher::EvidentPublisher) the [`AsyncSubscription`] was created from.
+    pub(crate) core: SubscriptionCore<'p, K, M, T, F>,
+
+    /// The channel [`Receiver`] used to receive captured events from the [`EvidentPublisher`](crate::publisher::EvidentPublisher).
+    pub(crate) receiver: Receiver<Arc<Event<K, M, T>>>,
+}
+
+impl<'p, K, M, T, F> AsyncSubscription<'p, K, M, T, F>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+    T: EventEntry<K, M>,
+    F: Filter<K, M>,
+{
+    /// Receives the next captured event,
+    /// or `None` if the subscription channel was closed by the [`EvidentPublisher`](crate::publisher::EvidentPublisher).
+    pub async fn recv(&mut self) -> Option<Arc<Event<K, M, T>>> {
+        self.receiver.next().await
+    }
+
+    /// Unsubscribes this subscription.
+    pub fn unsubscribe(self) {
+        drop(self)
+    }
+
+    /// Returns the ID of the subscription channel.
+    pub fn get_channel_id(&self) -> crate::uuid::Uuid {
+        self.core.channel_id
+    }
+
+    /// Returns the number of events that could not be sent to this subscription since last reset,
+    /// because the subscription channel was full.
+    pub fn get_missed_events(&self) -> usize {
+        self.core.missed_events.load(Ordering::Relaxed)
+    }
+
+    /// Resets the number of missed events of this subscription.
+    pub fn reset_missed_events(&self) {
+        self.core.missed_events.store(0, Ordering::Relaxed);
+    }
+
+    /// Unsubscribes from the given event-ID.
+    ///
+    /// See [`Subscription::unsubscribe_id`](crate::subscription::Subscription::unsubscribe_id) for possible errors.
+    pub fn unsubscribe_id(&mut self, id: K) -> Result<(), SubscriptionError<K>> {
+        self.core.unsubscribe_many(vec![id])
+    }
+
+    /// Unsubscribes from the given list of event-IDs.
+    ///
+    /// See [`Subscription::unsubscribe_many`](crate::subscription::Subscription::unsubscribe_many) for possible errors.
+    pub fn unsubscribe_many(&mut self, ids: Vec<K>) -> Result<(), SubscriptionError<K>> {
+        self.core.unsubscribe_many(ids)
+    }
+
+    /// Subscribes to the given event-ID.
+    ///
+    /// See [`Subscription::subscribe_id`](crate::subscription::Subscription::subscribe_id) for possible errors.
+    ///
+    /// [req:subs.specific.one]
+    pub fn subscribe_id(&mut self, id: K) -> Result<(), SubscriptionError<K>> {
+        self.core.subscribe_many(vec![id])
+    }
+
+    /// Subscribes to the given list of event-IDs.
+    ///
+    /// See [`Subscription::subscribe_many`](crate::subscription::Subscription::subscribe_many) for possible errors.
+    ///
+    /// [req:subs.specific.mult]
+    pub fn subscribe_many(&mut self, ids: Vec<K>) -> Result<(), SubscriptionError<K>> {
+        self.core.subscribe_many(ids)
+    }
+}
+
+impl<'p, K, M, T, F> Stream for AsyncSubscription<'p, K, M, T, F>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+    T: EventEntry<K, M>,
+    F: Filter<K, M>,
+{
+    type Item = Arc<Event<K, M, T>>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_next_unpin(cx)
+    }
+}
+
+// Note: No field is structurally pinned, so the subscription may be moved freely.
+impl<'p, K, M, T, F> Unpin for AsyncSubscription<'p, K, M, T, F>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+    T: EventEntry<K, M>,
+    F: Filter<K, M>,
+{
+}
+
+impl<'p, K, M, T, F> PartialEq for AsyncSubscription<'p, K, M, T, F>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+    T: EventEntry<K, M>,
+    F: Filter<K, M>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.core.channel_id == other.core.channel_id
+    }
+}
+
+impl<'p, K, M, T, F> Eq for AsyncSubscription<'p, K, M, T, F>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+    T: EventEntry<K, M>,
+    F: Filter<K, M>,
+{
+}
+
+impl<'p, K, M, T, F> Hash for AsyncSubscription<'p, K, M, T, F>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+    T: EventEntry<K, M>,
+    F: Filter<K, M>,
+{
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.core.channel_id.hash(state);
+    }
+}
@@ -0,0 +1,46 @@
+//! Contains the [`BridgedEvent`] used to capture events of other logging frameworks.
+
+use std::marker::PhantomData;
+
+use crate::event::{entry::EventEntry, intermediary::IntermediaryEvent, origin::Origin, Id, Msg};
+
+/// Intermediary event used to capture events of other logging frameworks.
+///
+/// **Note:** Does not capture itself on `drop()`, so it must be captured explicitly using `_capture()` of the publisher.
+pub(crate) struct BridgedEvent<K, M, T>
+where
+    K: Id,
+    M: Msg,
+    T: EventEntry<K, M>,
+{
+    entry: Option<T>,
+
+    // PahmtomData needed for unused generics
+    phantom_k: PhantomData<K>,
+    phantom_m: PhantomData<M>,
+}
+
+impl<K, M, T> IntermediaryEvent<K, M, T> for BridgedEvent<K, M, T>
+where
+    K: Id,
+    M: Msg,
+    T: EventEntry<K, M>,
+{
+    fn new(event_id: K, msg: Option<impl Into<M>>, origin: Origin) -> Self {
+        BridgedEvent {
+            entry: Some(T::new(event_id, msg, origin)),
+            phantom_k: PhantomData,
+            phantom_m: PhantomData,
+        }
+    }
+
+    fn get_entry(&self) -> &T {
+        self.entry
+            .as_ref()
+            .expect("Entry of bridged event was already taken.")
+    }
+
+    fn take_entry(&mut self) -> Option<T> {
+        self.entry.take()
+    }
+}
@@ -0,0 +1,330 @@
+//! Contains the bounded channel used between subscriptions and an [`EvidentPublisher`](crate::publisher::EvidentPublisher).
+//!
+//! The channel mirrors [`sync_channel`](std::sync::mpsc::sync_channel) of the standard library,
+//! but allows the sender to drop the oldest element if the channel is full, and to wait for free space with a timeout.
+//!
+//! **Note:** Subscriptions used the [`Receiver`](std::sync::mpsc::Receiver) of the standard library before.
+//! This [`Receiver`] provides the same receive functions, but code naming the receiver type,
+//! or passing it to functions expecting a [`std::sync::mpsc::Receiver`] must be updated.
+//!
+//! [req:subs]
+
+use std::{
+    collections::VecDeque,
+    sync::{
+        mpsc::{RecvError, RecvTimeoutError, SendError, TryRecvError, TrySendError},
+        Arc, Condvar, Mutex, MutexGuard,
+    },
+    time::{Duration, Instant},
+};
+
+/// Creates a new bounded channel, and returns its *sender-part* and *receiver-part*.
+///
+/// **Note:** A bound of `0` is treated as bound of `1`, because the channel always buffers elements.
+pub(crate) fn channel<E>(bound: usize) -> (Sender<E>, Receiver<E>) {
+    let shared = Arc::new(Shared {
+        state: Mutex::new(State {
+            elements: VecDeque::new(),
+            senders: 1,
+            receiver_alive: true,
+        }),
+        not_empty: Condvar::new(),
+        not_full: Condvar::new(),
+        bound: bound.max(1),
+    });
+
+    (
+        Sender {
+            shared: shared.clone(),
+        },
+        Receiver { shared },
+    )
+}
+
+/// State shared between all [`Sender`]s and the [`Receiver`] of a channel.
+struct Shared<E> {
+    state: Mutex<State<E>>,
+    not_empty: Condvar,
+    not_full: Condvar,
+    bound: usize,
+}
+
+struct State<E> {
+    elements: VecDeque<E>,
+    /// Number of existing [`Sender`]s.
+    senders: usize,
+    /// Flag set to `false` once the [`Receiver`] is dropped.
+    receiver_alive: bool,
+}
+
+impl<E> Shared<E> {
+    fn lock(&self) -> MutexGuard<'_, State<E>> {
+        // Note: The lock is never held while user code runs, so a poisoned state is still consistent.
+        match self.state.lock() {
+            Ok(state) => state,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+}
+
+/// *Sender-part* of a channel created with [`channel`].
+pub(crate) struct Sender<E> {
+    shared: Arc<Shared<E>>,
+}
+
+impl<E> Sender<E> {
+    /// Sends the element, blocking the current thread while the channel is full.
+    ///
+    /// Returns the element as error if the [`Receiver`] is dropped.
+    pub(crate) fn send(&self, element: E) -> Result<(), SendError<E>> {
+        let mut state = self.shared.lock();
+
+        while state.receiver_alive && state.elements.len() >= self.shared.bound {
+            state = match self.shared.not_full.wait(state) {
+                Ok(state) => state,
+                Err(poisoned) => poisoned.into_inner(),
+            };
+        }
+
+        self.push(state, element).map_err(SendError)
+    }
+
+    /// Sends the element without blocking the current thread.
+    pub(crate) fn try_send(&self, element: E) -> Result<(), TrySendError<E>> {
+        let state = self.shared.lock();
+
+        if state.receiver_alive && state.elements.len() >= self.shared.bound {
+            return Err(TrySendError::Full(element));
+        }
+
+        self.push(state, element)
+            .map_err(TrySendError::Disconnected)
+    }
+
+    /// Sends the element, blocking the current thread while the channel is full, but at most for the given timeout.
+    ///
+    /// Returns [`TrySendError::Full`] if the channel is still full once the timeout is reached.
+    pub(crate) fn send_timeout(
+        &self,
+        element: E,
+        timeout: Duration,
+    ) -> Result<(), TrySendError<E>> {
+        let deadline = Instant::now() + timeout;
+        let mut state = self.shared.lock();
+
+        while state.receiver_alive && state.elements.len() >= self.shared.bound {
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(TrySendError::Full(element));
+            }
+
+            state = match self.shared.not_full.wait_timeout(state, deadline - now) {
+                Ok((state, _)) => state,
+                Err(poisoned) => poisoned.into_inner().0,
+            };
+        }
+
+        self.push(state, element)
+            .map_err(TrySendError::Disconnected)
+    }
+
+    /// Sends the element without blocking the current thread, dropping the oldest buffered element if the channel is full.
+    ///
+    /// Returns `true` if the oldest element was dropped, or the element as error if the [`Receiver`] is dropped.
+    pub(crate) fn force_send(&self, element: E) -> Result<bool, SendError<E>> {
+        let mut state = self.shared.lock();
+
+        let mut dropped_oldest = false;
+        if state.receiver_alive && state.elements.len() >= self.shared.bound {
+            state.elements.pop_front();
+            dropped_oldest = true;
+        }
+
+        self.push(state, element).map_err(SendError)?;
+        Ok(dropped_oldest)
+    }
+
+    /// Sends the element without blocking the current thread, even if the channel is full.
+    ///
+    /// Returns the element as error if the [`Receiver`] is dropped.
+    pub(crate) fn send_unbounded(&self, element: E) -> Result<(), SendError<E>> {
+        let state = self.shared.lock();
+        self.push(state, element).map_err(SendError)
+    }
+
+    /// Pushes the element into the locked channel, and wakes up the [`Receiver`].
+    ///
+    /// Returns the element as error if the [`Receiver`] is dropped.
+    fn push(&self, mut state: MutexGuard<'_, State<E>>, element: E) -> Result<(), E> {
+        if !state.receiver_alive {
+            return Err(element);
+        }
+
+        state.elements.push_back(element);
+        self.shared.not_empty.notify_one();
+        Ok(())
+    }
+}
+
+impl<E> Clone for Sender<E> {
+    fn clone(&self) -> Self {
+        self.shared.lock().senders += 1;
+
+        Sender {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<E> Drop for Sender<E> {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+        state.senders -= 1;
+
+        if state.senders == 0 {
+            // Wakes up the receiver, so it may return once all remaining elements are received.
+            self.shared.not_empty.notify_all();
+        }
+    }
+}
+
+/// *Receiver-part* of the channel between a subscription and an [`EvidentPublisher`](crate::publisher::EvidentPublisher).
+///
+/// Provides the same functions as the [`Receiver`](std::sync::mpsc::Receiver) of the standard library.
+/// The channel is disconnected once the publisher removed the subscription, or was shut down,
+/// and all buffered events were received.
+///
+/// [req:subs]
+pub struct Receiver<E> {
+    shared: Arc<Shared<E>>,
+}
+
+impl<E> Receiver<E> {
+    /// Blocks the current thread until an element is received.
+    ///
+    /// Returns an error if the channel is empty and disconnected.
+    pub fn recv(&self) -> Result<E, RecvError> {
+        let mut state = self.shared.lock();
+
+        loop {
+            if let Some(element) = self.pop(&mut state) {
+                return Ok(element);
+            }
+            if state.senders == 0 {
+                return Err(RecvError);
+            }
+
+            state = match self.shared.not_empty.wait(state) {
+                Ok(state) => state,
+                Err(poisoned) => poisoned.into_inner(),
+            };
+        }
+    }
+
+    /// Receives an element without blocking the current thread.
+    pub fn try_recv(&self) -> Result<E, TryRecvError> {
+        let mut state = self.shared.lock();
+
+        match self.pop(&mut state) {
+            Some(element) => Ok(element),
+            None if state.senders == 0 => Err(TryRecvError::Disconnected),
+            None => Err(TryRecvError::Empty),
+        }
+    }
+
+    /// Blocks the current thread until an element is received, or the timeout is reached.
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<E, RecvTimeoutError> {
+        let deadline = Instant::now() + timeout;
+        let mut state = self.shared.lock();
+
+        loop {
+            if let Some(element) = self.pop(&mut state) {
+                return Ok(element);
+            }
+            if state.senders == 0 {
+                return Err(RecvTimeoutError::Disconnected);
+            }
+
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(RecvTimeoutError::Timeout);
+            }
+
+            state = match self.shared.not_empty.wait_timeout(state, deadline - now) {
+                Ok((state, _)) => state,
+                Err(poisoned) => poisoned.into_inner().0,
+            };
+        }
+    }
+
+    /// Returns an iterator that blocks to receive elements until the channel is disconnected.
+    pub fn iter(&self) -> Iter<'_, E> {
+        Iter { receiver: self }
+    }
+
+    /// Returns an iterator over all elements that are currently buffered in the channel.
+    pub fn try_iter(&self) -> TryIter<'_, E> {
+        TryIter { receiver: self }
+    }
+
+    /// Pops the oldest element from the locked channel, and wakes up a waiting sender.
+    fn pop(&self, state: &mut MutexGuard<'_, State<E>>) -> Option<E> {
+        let element = state.elements.pop_front()?;
+        self.shared.not_full.notify_one();
+        Some(element)
+    }
+}
+
+impl<E> Drop for Receiver<E> {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+        state.receiver_alive = false;
+        state.elements.clear();
+
+        // Wakes up all blocked senders, so they notice the disconnected channel.
+        self.shared.not_full.notify_all();
+    }
+}
+
+impl<E> std::fmt::Debug for Receiver<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Receiver").finish_non_exhaustive()
+    }
+}
+
+impl<'a, E> IntoIterator for &'a Receiver<E> {
+    type Item = E;
+    type IntoIter = Iter<'a, E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator that blocks to receive elements of a [`Receiver`] until the channel is disconnected.
+#[derive(Debug)]
+pub struct Iter<'a, E> {
+    receiver: &'a Receiver<E>,
+}
+
+impl<E> Iterator for Iter<'_, E> {
+    type Item = E;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.receiver.recv().ok()
+    }
+}
+
+/// Iterator over all elements that are currently buffered in a [`Receiver`].
+#[derive(Debug)]
+pub struct TryIter<'a, E> {
+    receiver: &'a Receiver<E>,
+}
+
+impl<E> Iterator for TryIter<'_, E> {
+    type Item = E;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.receiver.try_recv().ok()
+    }
+}
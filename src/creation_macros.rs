@@ -57,6 +57,47 @@
 /// );
 /// ```
 ///
+/// **Form with named options:**
+///
+/// After the types, all other parameters are optional and may be set in any order.
+/// Without any option, the publisher uses the defaults of the builder and no filter.
+/// Every option except `filter_type` sets the [`EvidentPublisherBuilder`](crate::publisher::EvidentPublisherBuilder) setting of the same name,
+/// so options not set use the defaults of the builder.
+///
+/// ```text
+/// evident::create_static_publisher!(
+///     pub MY_PUBLISHER,
+///     id_type = MyId,
+///     msg_type = String,
+///     entry_type = MyEventEntry,
+///     interm_event_type = MyIntermEvent,
+///     thread_name = "my-publisher",
+///     capture_mode = CaptureMode::NonBlocking,
+///     filter_type = MyFilter,
+///     filter = MyFilter::default(),
+///     stack_size = 64 * 1024,
+/// );
+/// ```
+///
+/// **Short form:**
+///
+/// The short form only takes the ID and message types, and uses [`DefaultEntry`](crate::event::entry::DefaultEntry)
+/// and [`DefaultIntermediaryEvent`](crate::event::intermediary::DefaultIntermediaryEvent) without filter.
+/// Channel bounds are set to [`DEFAULT_CAPTURE_CHANNEL_BOUND`](crate::publisher::DEFAULT_CAPTURE_CHANNEL_BOUND)
+/// and [`DEFAULT_SUBSCRIPTION_CHANNEL_BOUND`](crate::publisher::DEFAULT_SUBSCRIPTION_CHANNEL_BOUND),
+/// and capture mode and timestamp kind are set to their defaults.
+///
+/// ```text
+/// evident::create_static_publisher!(
+///     pub MY_PUBLISHER,
+///     id_type = MyId,
+///     msg_type = String
+/// );
+/// ```
+///
+/// **Note:** The short form implements [`DefaultPublisher`](crate::event::intermediary::DefaultPublisher) for the ID type,
+/// so only one short form publisher may be created per ID and message type.
+///
 /// [req:qa.ux.macros]
 #[macro_export]
 macro_rules! create_static_publisher {
@@ -113,6 +154,138 @@ macro_rules! create_static_publisher {
             $(, filter=$filter)?
         );
     };
+    ($visibility:vis $publisher_name:ident,
+        id_type = $id_t:ty,
+        msg_type = $msg_t:ty,
+        entry_type = $entry_t:ty,
+        interm_event_type = $interm_event_t:ty
+        $(, $($options:tt)*)?
+    ) => {
+        $crate::z__setup_configured_publisher!(
+            @parse [$publisher_name, $id_t, $msg_t, $entry_t, $interm_event_t, [$visibility]],
+            filter_type = [$crate::event::filter::DummyFilter<$id_t, $msg_t>],
+            settings = [],
+            $($($options)*)?
+        );
+    };
+    ($publisher_name:ident,
+        id_type = $id_t:ty,
+        msg_type = $msg_t:ty
+    ) => {
+        $crate::z__setup_default_publisher!($publisher_name, $id_t, $msg_t);
+    };
+    ($visibility:vis $publisher_name:ident,
+        id_type = $id_t:ty,
+        msg_type = $msg_t:ty
+    ) => {
+        $crate::z__setup_default_publisher!($publisher_name, $id_t, $msg_t, scope = $visibility);
+    };
+}
+
+/// Internal macro to set up a static publisher using named options in any order.
+///
+/// **Note:** Use [`create_static_publisher`](crate::create_static_publisher) instead.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! z__setup_configured_publisher {
+    (@parse [$publisher_name:ident, $id_t:ty, $msg_t:ty, $entry_t:ty, $interm_event_t:ty, [$visibility:vis]],
+        filter_type = [$filter_t:ty],
+        settings = [$($settings:tt)*],
+    ) => {
+        $visibility static $publisher_name: $crate::once_cell::sync::Lazy<
+            $crate::publisher::EvidentPublisher<$id_t, $msg_t, $entry_t, $filter_t>,
+        > = $crate::once_cell::sync::Lazy::new(|| {
+            $crate::publisher::EvidentPublisherBuilder::<
+                $id_t,
+                $msg_t,
+                $entry_t,
+                $filter_t
+            >::new()
+                $($settings)*
+                .build(|event| {
+                    $publisher_name.on_event(event);
+                })
+        });
+
+        $crate::z__impl_publisher_traits!(
+            $publisher_name,
+            $id_t,
+            $msg_t,
+            $entry_t,
+            $interm_event_t
+        );
+    };
+    (@parse $setup:tt,
+        filter_type = $prev_filter_t:tt,
+        settings = [$($settings:tt)*],
+        filter_type = $filter_t:ty
+        $(, $($rest:tt)*)?
+    ) => {
+        $crate::z__setup_configured_publisher!(
+            @parse $setup,
+            filter_type = [$filter_t],
+            settings = [$($settings)*],
+            $($($rest)*)?
+        );
+    };
+    (@parse $setup:tt,
+        filter_type = $filter_t:tt,
+        settings = [$($settings:tt)*],
+        $option:ident = $value:expr
+        $(, $($rest:tt)*)?
+    ) => {
+        $crate::z__setup_configured_publisher!(
+            @parse $setup,
+            filter_type = $filter_t,
+            settings = [$($settings)* .$option($value)],
+            $($($rest)*)?
+        );
+    };
+}
+
+/// Internal macro to set up a static publisher using the default entry and intermediary event.
+///
+/// **Note:** Use [`create_static_publisher`](crate::create_static_publisher) instead.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! z__setup_default_publisher {
+    ($publisher_name:ident,
+        $id_t:ty,
+        $msg_t:ty
+        $(, scope=$visibility:vis)?
+    ) => {
+        $crate::z__create_static_publisher!(
+            $publisher_name,
+            $id_t,
+            $msg_t,
+            $crate::event::entry::DefaultEntry<$id_t, $msg_t>,
+            $crate::event::intermediary::DefaultIntermediaryEvent<$id_t, $msg_t>,
+            $crate::publisher::DEFAULT_CAPTURE_CHANNEL_BOUND,
+            $crate::publisher::DEFAULT_SUBSCRIPTION_CHANNEL_BOUND,
+            $crate::publisher::CaptureMode::default(),
+            $crate::publisher::EventTimestampKind::default()
+            $(, scope=$visibility)?
+        );
+
+        impl $crate::event::intermediary::DefaultPublisher<$msg_t> for $id_t {
+            type Filter = $crate::event::filter::DummyFilter<$id_t, $msg_t>;
+
+            fn publisher() -> &'static $crate::publisher::EvidentPublisher<
+                $id_t,
+                $msg_t,
+                $crate::event::entry::DefaultEntry<$id_t, $msg_t>,
+                Self::Filter,
+            > {
+                &$publisher_name
+            }
+        }
+
+        impl From<$crate::event::intermediary::DefaultIntermediaryEvent<$id_t, $msg_t>> for $id_t {
+            fn from(intermed_event: $crate::event::intermediary::DefaultIntermediaryEvent<$id_t, $msg_t>) -> Self {
+                intermed_event.finalize().into_event_id()
+            }
+        }
+    };
 }
 
 /// Internal macro to set up a static publisher.
@@ -150,6 +323,28 @@ macro_rules! z__setup_static_publisher {
             $(, scope=$visibility)?
         );
 
+        $crate::z__impl_publisher_traits!(
+            $publisher_name,
+            $id_t,
+            $msg_t,
+            $entry_t,
+            $interm_event_t
+        );
+    };
+}
+
+/// Internal macro to implement the traits needed to capture events of a custom intermediary event with a static publisher.
+///
+/// **Note:** Use [`create_static_publisher`](crate::create_static_publisher) instead.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! z__impl_publisher_traits {
+    ($publisher_name:ident,
+        $id_t:ty,
+        $msg_t:ty,
+        $entry_t:ty,
+        $interm_event_t:ty
+    ) => {
         impl Drop for $interm_event_t {
             fn drop(&mut self) {
                 $publisher_name._capture(self);
@@ -159,27 +354,50 @@ macro_rules! z__setup_static_publisher {
         // Note: Re-impl `finalize()` for better IntelliSense.
         impl $interm_event_t {
             pub fn finalize(self) -> $crate::event::finalized::FinalizedEvent<$id_t> {
-                $crate::event::intermediary::IntermediaryEvent::<$id_t, $msg_t, $entry_t>::finalize(self)
+                $crate::event::intermediary::IntermediaryEvent::<$id_t, $msg_t, $entry_t>::finalize(
+                    self,
+                )
+            }
+
+            /// Finalizes the event, and blocks until it was dispatched to all its subscriptions,
+            /// or the optional timeout was reached.
+            pub fn finalize_and_wait(
+                self,
+                timeout: Option<std::time::Duration>,
+            ) -> Result<
+                $crate::event::finalized::FinalizedEvent<$id_t>,
+                $crate::publisher::DispatchError,
+            > {
+                $publisher_name.finalize_and_wait(self, timeout)
+            }
+
+            /// Finalizes the event, and returns the outcome of capturing it.
+            pub fn finalize_checked(self) -> $crate::publisher::CaptureOutcome {
+                $publisher_name.finalize_checked(self)
             }
         }
 
         impl From<$interm_event_t> for $id_t {
             fn from(intermed_event: $interm_event_t) -> Self {
-                $crate::event::intermediary::IntermediaryEvent::<$id_t, $msg_t, $entry_t>::finalize(intermed_event).into_event_id()
+                $crate::event::intermediary::IntermediaryEvent::<$id_t, $msg_t, $entry_t>::finalize(
+                    intermed_event,
+                )
+                .into_event_id()
             }
         }
 
         impl PartialEq for $entry_t {
             fn eq(&self, other: &Self) -> bool {
-                $crate::event::entry::EventEntry::<$id_t, $msg_t>::get_event_id(self) == $crate::event::entry::EventEntry::<$id_t, $msg_t>::get_event_id(other)
-                && $crate::event::entry::EventEntry::<$id_t, $msg_t>::get_entry_id(self) == $crate::event::entry::EventEntry::<$id_t, $msg_t>::get_entry_id(other)
+                $crate::event::entry::EventEntry::<$id_t, $msg_t>::get_event_id(self)
+                    == $crate::event::entry::EventEntry::<$id_t, $msg_t>::get_event_id(other)
+                    && $crate::event::entry::EventEntry::<$id_t, $msg_t>::get_entry_id(self)
+                        == $crate::event::entry::EventEntry::<$id_t, $msg_t>::get_entry_id(other)
             }
         }
 
         impl Eq for $entry_t {}
 
-        impl std::hash::Hash for $entry_t
-        {
+        impl std::hash::Hash for $entry_t {
             fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
                 $crate::event::entry::EventEntry::<$id_t, $msg_t>::get_entry_id(self).hash(state);
             }
@@ -285,6 +503,16 @@ macro_rules! z__create_static_publisher {
 /// );
 /// ```
 ///
+/// **Example for publishers created with the short form of [`create_static_publisher!()`](crate::create_static_publisher):**
+///
+/// ```text
+/// evident::create_set_event_macro!(
+///     no_export,
+///     id_type = my_crate::my_mod::MyId,
+///     msg_type = String
+/// );
+/// ```
+///
 /// [req:qa.ux.macros]
 #[macro_export]
 macro_rules! create_set_event_macro {
@@ -378,4 +606,26 @@ macro_rules! create_set_event_macro {
             };
         }
     };
+    (id_type = $id_t:ty,
+        msg_type = $msg_t:ty
+    ) => {
+        $crate::create_set_event_macro!(
+            id_type = $id_t,
+            msg_type = $msg_t,
+            entry_type = $crate::event::entry::DefaultEntry<$id_t, $msg_t>,
+            interm_event_type = $crate::event::intermediary::DefaultIntermediaryEvent<$id_t, $msg_t>
+        );
+    };
+    (no_export,
+        id_type = $id_t:ty,
+        msg_type = $msg_t:ty
+    ) => {
+        $crate::create_set_event_macro!(
+            no_export,
+            id_type = $id_t,
+            msg_type = $msg_t,
+            entry_type = $crate::event::entry::DefaultEntry<$id_t, $msg_t>,
+            interm_event_type = $crate::event::intermediary::DefaultIntermediaryEvent<$id_t, $msg_t>
+        );
+    };
 }
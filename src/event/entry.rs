@@ -6,6 +6,10 @@ use std::hash::Hash;
 
 use super::{origin::Origin, Id, Msg};
 
+/// Derive macro for the [`EventEntry`] trait.
+#[cfg(feature = "derive")]
+pub use evident_derive::EventEntry;
+
 /// Trait that must be implemented for a custom *evident* event-entry.\
 /// This implementation must then be used for implementations of the traits [`EventEntry`] and [`IntermediaryEvent`](super::intermediary::IntermediaryEvent).\
 /// All implementations are needed to create an *evident* publisher using the [`create_static_publisher!()`](crate::create_static_publisher) macro.
@@ -29,6 +33,26 @@ pub trait EventEntry<K: Id, M: Msg>: Default + Clone + Hash + Send + Sync + 'sta
     /// [req:event.entry], [req:event.id], [req:event.msg], [req:event.origin]
     fn new(event_id: K, msg: Option<impl Into<M>>, origin: Origin) -> Self;
 
+    /// Creates a new [`EventEntry`] with the given entry-ID.
+    ///
+    /// Used to re-create entries of events that were set before (e.g. events read from a journal).
+    ///
+    /// **Note:** The default implementation calls [`new`](Self::new), so a new entry-ID is generated.
+    /// Override this function to keep the given entry-ID.
+    /// Otherwise, events re-created from an [`EventRecord`](super::record::EventRecord)
+    /// (e.g. events replayed from a journal of the `journal` feature) silently get new entry-IDs.
+    ///
+    /// [req:event.entry], [req:event.entry.id]
+    fn with_entry_id(
+        event_id: K,
+        entry_id: crate::uuid::Uuid,
+        msg: Option<impl Into<M>>,
+        origin: Origin,
+    ) -> Self {
+        let _ = entry_id;
+        Self::new(event_id, msg, origin)
+    }
+
     /// Returns the [`Id`] of this event.
     ///
     /// [req:event.id]
@@ -55,3 +79,82 @@ pub trait EventEntry<K: Id, M: Msg>: Default + Clone + Hash + Send + Sync + 'sta
     /// [req:event.origin]
     fn get_origin(&self) -> &Origin;
 }
+
+/// Generic [`EventEntry`] that only stores the event-ID, message, entry-ID, and origin of an event.
+///
+/// **Note:** May be used if no custom information must be added to events.
+/// See [`create_static_publisher!()`](crate::create_static_publisher) for a short form that uses this entry.
+///
+/// [req:event.entry], [req:event.entry.generic]
+#[derive(Debug, Clone)]
+pub struct DefaultEntry<K: Id, M: Msg> {
+    event_id: K,
+    msg: Option<M>,
+    entry_id: crate::uuid::Uuid,
+    origin: Origin,
+}
+
+impl<K: Id, M: Msg> Default for DefaultEntry<K, M> {
+    fn default() -> Self {
+        DefaultEntry {
+            event_id: K::default(),
+            msg: None,
+            entry_id: crate::uuid::Uuid::default(),
+            origin: Origin::default(),
+        }
+    }
+}
+
+impl<K: Id, M: Msg> EventEntry<K, M> for DefaultEntry<K, M> {
+    fn new(event_id: K, msg: Option<impl Into<M>>, origin: Origin) -> Self {
+        Self::with_entry_id(event_id, crate::uuid::Uuid::new_v4(), msg, origin)
+    }
+
+    fn with_entry_id(
+        event_id: K,
+        entry_id: crate::uuid::Uuid,
+        msg: Option<impl Into<M>>,
+        origin: Origin,
+    ) -> Self {
+        DefaultEntry {
+            event_id,
+            msg: msg.map(|m| m.into()),
+            entry_id,
+            origin,
+        }
+    }
+
+    fn get_event_id(&self) -> &K {
+        &self.event_id
+    }
+
+    fn into_event_id(self) -> K {
+        self.event_id
+    }
+
+    fn get_entry_id(&self) -> crate::uuid::Uuid {
+        self.entry_id
+    }
+
+    fn get_msg(&self) -> Option<&M> {
+        self.msg.as_ref()
+    }
+
+    fn get_origin(&self) -> &Origin {
+        &self.origin
+    }
+}
+
+impl<K: Id, M: Msg> PartialEq for DefaultEntry<K, M> {
+    fn eq(&self, other: &Self) -> bool {
+        self.event_id == other.event_id && self.entry_id == other.entry_id
+    }
+}
+
+impl<K: Id, M: Msg> Eq for DefaultEntry<K, M> {}
+
+impl<K: Id, M: Msg> Hash for DefaultEntry<K, M> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.entry_id.hash(state);
+    }
+}
@@ -1,12 +1,38 @@
-//! Contains the [`Filter`] trait.
+//! Contains the [`Filter`] trait, and filters that may be combined to create custom filters.
+//!
+//! **Example:**
+//!
+//! ```ignore
+//! let filter = AllowIds::new([SOME_ID, OTHER_ID])
+//!     .or(ModulePrefix::new("my_crate::network"))
+//!     .and(DenyIds::new([NOISY_ID]));
+//! ```
+//!
+//! The stateful filters [`RateLimit`], [`OriginRateLimit`], and [`Sample`] count the entries they suppressed.
+//! Use `get_filter()` of the publisher to access the counts.
+//!
+//! [req:cap.filter]
 
-use std::marker::PhantomData;
+use std::{
+    collections::{hash_map::RandomState, HashMap, HashSet},
+    hash::{BuildHasher, Hash, Hasher},
+    marker::PhantomData,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
 
 use crate::publisher::CaptureControl;
 
 use super::{entry::EventEntry, Id, Msg};
 
 /// The [`Filter`] trait allows to add a custom filter to an [`EvidentPublisher`].
+///
+/// Filters may be combined using [`and`](Self::and), [`or`](Self::or), and [`not`](Self::not).
+///
+/// **Note:** Control-IDs are never filtered by the publisher.
 pub trait Filter<K, M>
 where
     K: Id + CaptureControl,
@@ -14,6 +40,42 @@ where
 {
     /// Return `true` if the entry is allowed to be captured.
     fn allow_entry(&self, entry: &impl EventEntry<K, M>) -> bool;
+
+    /// Combines this filter with the given filter, so entries must be allowed by both filters.
+    ///
+    /// **Note:** The other filter is only applied if this filter allows the entry.
+    fn and<O>(self, other: O) -> And<Self, O>
+    where
+        Self: Sized,
+        O: Filter<K, M>,
+    {
+        And {
+            first: self,
+            second: other,
+        }
+    }
+
+    /// Combines this filter with the given filter, so entries must be allowed by at least one of the filters.
+    ///
+    /// **Note:** The other filter is only applied if this filter does not allow the entry.
+    fn or<O>(self, other: O) -> Or<Self, O>
+    where
+        Self: Sized,
+        O: Filter<K, M>,
+    {
+        Or {
+            first: self,
+            second: other,
+        }
+    }
+
+    /// Inverts this filter, so only entries that are not allowed by this filter are allowed.
+    fn not(self) -> Not<Self>
+    where
+        Self: Sized,
+    {
+        Not { inner: self }
+    }
 }
 
 /// Struct used to provide a dummy filter in case no custom filter is set.
@@ -36,3 +98,522 @@ where
         true
     }
 }
+
+/// Filter that only allows entries with one of the given event-IDs.
+#[derive(Debug, Clone)]
+pub struct AllowIds<K, M>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+{
+    ids: HashSet<K>,
+
+    // PahmtomData needed for unused generics
+    phantom_m: PhantomData<M>,
+}
+
+impl<K, M> AllowIds<K, M>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+{
+    /// Creates a filter that only allows entries with one of the given event-IDs.
+    pub fn new(ids: impl IntoIterator<Item = K>) -> Self {
+        AllowIds {
+            ids: ids.into_iter().collect(),
+            phantom_m: PhantomData,
+        }
+    }
+}
+
+impl<K, M> Filter<K, M> for AllowIds<K, M>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+{
+    fn allow_entry(&self, entry: &impl EventEntry<K, M>) -> bool {
+        self.ids.contains(entry.get_event_id())
+    }
+}
+
+/// Filter that allows all entries, except entries with one of the given event-IDs.
+#[derive(Debug, Clone)]
+pub struct DenyIds<K, M>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+{
+    ids: HashSet<K>,
+
+    // PahmtomData needed for unused generics
+    phantom_m: PhantomData<M>,
+}
+
+impl<K, M> DenyIds<K, M>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+{
+    /// Creates a filter that denies entries with one of the given event-IDs.
+    pub fn new(ids: impl IntoIterator<Item = K>) -> Self {
+        DenyIds {
+            ids: ids.into_iter().collect(),
+            phantom_m: PhantomData,
+        }
+    }
+}
+
+impl<K, M> Filter<K, M> for DenyIds<K, M>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+{
+    fn allow_entry(&self, entry: &impl EventEntry<K, M>) -> bool {
+        !self.ids.contains(entry.get_event_id())
+    }
+}
+
+/// Filter that only allows entries whose origin module path starts with the given prefix.
+///
+/// **Note:** The prefix must match whole path segments, so prefix `my_crate::net` allows `my_crate::net::tcp`, but not `my_crate::network`.
+#[derive(Debug, Clone)]
+pub struct ModulePrefix<K, M>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+{
+    prefix: String,
+
+    // PahmtomData needed for unused generics
+    phantom_k: PhantomData<K>,
+    phantom_m: PhantomData<M>,
+}
+
+impl<K, M> ModulePrefix<K, M>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+{
+    /// Creates a filter that only allows entries set in the module with the given path, or any of its submodules.
+    pub fn new(prefix: impl Into<String>) -> Self {
+        ModulePrefix {
+            prefix: prefix.into(),
+            phantom_k: PhantomData,
+            phantom_m: PhantomData,
+        }
+    }
+}
+
+impl<K, M> Filter<K, M> for ModulePrefix<K, M>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+{
+    fn allow_entry(&self, entry: &impl EventEntry<K, M>) -> bool {
+        match entry
+            .get_origin()
+            .module_path
+            .strip_prefix(self.prefix.as_str())
+        {
+            Some(rest) => rest.is_empty() || rest.starts_with("::"),
+            None => false,
+        }
+    }
+}
+
+/// Filter that allows entries whose message satisfies the given predicate.
+///
+/// The predicate receives `None` for entries without message.
+///
+/// **Note:** Use a function pointer (e.g. `MsgPredicate<K, String, fn(Option<&String>) -> bool>`) to be able to name the filter type
+/// for the `filter_type` argument of [`create_static_publisher`](crate::create_static_publisher).
+#[derive(Debug, Clone)]
+pub struct MsgPredicate<K, M, P>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+    P: Fn(Option<&M>) -> bool,
+{
+    predicate: P,
+
+    // PahmtomData needed for unused generics
+    phantom_k: PhantomData<K>,
+    phantom_m: PhantomData<M>,
+}
+
+impl<K, M, P> MsgPredicate<K, M, P>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+    P: Fn(Option<&M>) -> bool,
+{
+    /// Creates a filter that allows entries whose message satisfies the given predicate.
+    pub fn new(predicate: P) -> Self {
+        MsgPredicate {
+            predicate,
+            phantom_k: PhantomData,
+            phantom_m: PhantomData,
+        }
+    }
+}
+
+impl<K, M, P> Filter<K, M> for MsgPredicate<K, M, P>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+    P: Fn(Option<&M>) -> bool,
+{
+    fn allow_entry(&self, entry: &impl EventEntry<K, M>) -> bool {
+        (self.predicate)(entry.get_msg())
+    }
+}
+
+/// Filter that allows entries that are allowed by both filters.
+///
+/// **Note:** Use [`Filter::and`] to create this filter.
+#[derive(Debug, Clone)]
+pub struct And<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<K, M, A, B> Filter<K, M> for And<A, B>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+    A: Filter<K, M>,
+    B: Filter<K, M>,
+{
+    fn allow_entry(&self, entry: &impl EventEntry<K, M>) -> bool {
+        self.first.allow_entry(entry) && self.second.allow_entry(entry)
+    }
+}
+
+/// Filter that allows entries that are allowed by at least one of the filters.
+///
+/// **Note:** Use [`Filter::or`] to create this filter.
+#[derive(Debug, Clone)]
+pub struct Or<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<K, M, A, B> Filter<K, M> for Or<A, B>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+    A: Filter<K, M>,
+    B: Filter<K, M>,
+{
+    fn allow_entry(&self, entry: &impl EventEntry<K, M>) -> bool {
+        self.first.allow_entry(entry) || self.second.allow_entry(entry)
+    }
+}
+
+/// Filter that allows entries that are not allowed by the inner filter.
+///
+/// **Note:** Use [`Filter::not`] to create this filter.
+#[derive(Debug, Clone)]
+pub struct Not<A> {
+    inner: A,
+}
+
+impl<K, M, A> Filter<K, M> for Not<A>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+    A: Filter<K, M>,
+{
+    fn allow_entry(&self, entry: &impl EventEntry<K, M>) -> bool {
+        !self.inner.allow_entry(entry)
+    }
+}
+
+/// Token buckets that allow a limited number of entries per key and period.
+#[derive(Debug)]
+struct TokenBuckets<B> {
+    capacity: f64,
+    tokens_per_sec: f64,
+    buckets: Mutex<HashMap<B, Bucket>>,
+    suppressed: AtomicU64,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl<B: Hash + Eq> TokenBuckets<B> {
+    fn new(max_entries: u32, period: Duration) -> Self {
+        TokenBuckets {
+            capacity: f64::from(max_entries),
+            tokens_per_sec: f64::from(max_entries) / period.as_secs_f64(),
+            buckets: Mutex::new(HashMap::new()),
+            suppressed: AtomicU64::new(0),
+        }
+    }
+
+    /// Takes one token of the bucket for the given key, and returns `true` if a token was available.
+    fn take(&self, key: B) -> bool {
+        let now = Instant::now();
+        let mut locked_buckets = match self.buckets.lock() {
+            Ok(locked_buckets) => locked_buckets,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let bucket = locked_buckets.entry(key).or_insert(Bucket {
+            tokens: self.capacity,
+            last_refill: now,
+        });
+
+        let refilled = now.duration_since(bucket.last_refill).as_secs_f64() * self.tokens_per_sec;
+        bucket.tokens = (bucket.tokens + refilled).min(self.capacity);
+        bucket.last_refill = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            true
+        } else {
+            self.suppressed.fetch_add(1, Ordering::Relaxed);
+            false
+        }
+    }
+
+    fn suppressed(&self) -> u64 {
+        self.suppressed.load(Ordering::Relaxed)
+    }
+}
+
+/// Filter that allows at most `max_entries` entries per event-ID within the given period,
+/// using a token bucket per event-ID.
+///
+/// Up to `max_entries` entries are allowed in a burst, and tokens are refilled evenly over the period.
+#[derive(Debug)]
+pub struct RateLimit<K, M>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+{
+    buckets: TokenBuckets<K>,
+
+    // PahmtomData needed for unused generics
+    phantom_m: PhantomData<M>,
+}
+
+impl<K, M> RateLimit<K, M>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+{
+    /// Creates a filter that allows at most `max_entries` entries per event-ID within the given period.
+    ///
+    /// # Panics
+    ///
+    /// If the period is zero.
+    pub fn new(max_entries: u32, period: Duration) -> Self {
+        assert!(
+            !period.is_zero(),
+            "Period of a rate limit must not be zero."
+        );
+
+        RateLimit {
+            buckets: TokenBuckets::new(max_entries, period),
+            phantom_m: PhantomData,
+        }
+    }
+
+    /// Returns the number of entries suppressed by this filter.
+    pub fn suppressed(&self) -> u64 {
+        self.buckets.suppressed()
+    }
+}
+
+impl<K, M> Filter<K, M> for RateLimit<K, M>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+{
+    fn allow_entry(&self, entry: &impl EventEntry<K, M>) -> bool {
+        self.buckets.take(entry.get_event_id().clone())
+    }
+}
+
+/// Filter that allows at most `max_entries` entries per origin (filename and line number) within the given period,
+/// using a token bucket per origin.
+///
+/// Up to `max_entries` entries are allowed in a burst, and tokens are refilled evenly over the period.
+#[derive(Debug)]
+pub struct OriginRateLimit<K, M>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+{
+    buckets: TokenBuckets<(&'static str, u32)>,
+
+    // PahmtomData needed for unused generics
+    phantom_k: PhantomData<K>,
+    phantom_m: PhantomData<M>,
+}
+
+impl<K, M> OriginRateLimit<K, M>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+{
+    /// Creates a filter that allows at most `max_entries` entries per origin within the given period.
+    ///
+    /// # Panics
+    ///
+    /// If the period is zero.
+    pub fn new(max_entries: u32, period: Duration) -> Self {
+        assert!(
+            !period.is_zero(),
+            "Period of a rate limit must not be zero."
+        );
+
+        OriginRateLimit {
+            buckets: TokenBuckets::new(max_entries, period),
+            phantom_k: PhantomData,
+            phantom_m: PhantomData,
+        }
+    }
+
+    /// Returns the number of entries suppressed by this filter.
+    pub fn suppressed(&self) -> u64 {
+        self.buckets.suppressed()
+    }
+}
+
+impl<K, M> Filter<K, M> for OriginRateLimit<K, M>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+{
+    fn allow_entry(&self, entry: &impl EventEntry<K, M>) -> bool {
+        let origin = entry.get_origin();
+        self.buckets.take((origin.filename, origin.line_nr))
+    }
+}
+
+/// Defines which entries are allowed by a [`Sample`] filter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SampleMode {
+    /// Every n-th entry is allowed.
+    OneIn(u64),
+    /// Entries are allowed with the given probability.
+    Probability(f64),
+}
+
+/// Filter that only allows a sample of all entries.
+///
+/// **Note:** Combine this filter with [`AllowIds`] using [`Filter::and`] to only sample entries of specific event-IDs.
+#[derive(Debug)]
+pub struct Sample<K, M>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+{
+    mode: SampleMode,
+    /// Number of entries checked for [`SampleMode::OneIn`], or the random state for [`SampleMode::Probability`].
+    state: AtomicU64,
+    suppressed: AtomicU64,
+
+    // PahmtomData needed for unused generics
+    phantom_k: PhantomData<K>,
+    phantom_m: PhantomData<M>,
+}
+
+impl<K, M> Sample<K, M>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+{
+    /// Creates a filter that allows the first entry, and then every n-th entry.
+    ///
+    /// # Panics
+    ///
+    /// If `n` is zero.
+    pub fn one_in(n: u64) -> Self {
+        assert!(n > 0, "Sample rate must not be zero.");
+        Self::create(SampleMode::OneIn(n), 0)
+    }
+
+    /// Creates a filter that allows entries with the given probability between `0.0` and `1.0`.
+    ///
+    /// # Panics
+    ///
+    /// If `probability` is not between `0.0` and `1.0`, or is NaN.
+    pub fn probability(probability: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&probability),
+            "Sample probability must be between 0.0 and 1.0."
+        );
+
+        // Note: Xorshift requires a non-zero state.
+        let seed = RandomState::new().build_hasher().finish() | 1;
+        Self::create(SampleMode::Probability(probability), seed)
+    }
+
+    fn create(mode: SampleMode, state: u64) -> Self {
+        Sample {
+            mode,
+            state: AtomicU64::new(state),
+            suppressed: AtomicU64::new(0),
+            phantom_k: PhantomData,
+            phantom_m: PhantomData,
+        }
+    }
+
+    /// Returns the number of entries suppressed by this filter.
+    pub fn suppressed(&self) -> u64 {
+        self.suppressed.load(Ordering::Relaxed)
+    }
+
+    /// Returns the next pseudo-random number using *xorshift64\**.
+    fn next_random(&self) -> u64 {
+        let xorshift = |mut x: u64| {
+            x ^= x >> 12;
+            x ^= x << 25;
+            x ^= x >> 27;
+            x
+        };
+
+        let prev = match self
+            .state
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |x| Some(xorshift(x)))
+        {
+            Ok(prev) | Err(prev) => prev,
+        };
+        xorshift(prev).wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+}
+
+impl<K, M> Filter<K, M> for Sample<K, M>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+{
+    // Note: `is_multiple_of()` requires Rust 1.87, which is newer than the supported Rust versions.
+    // Older clippy versions do not know the lint, so unknown lints must be allowed as well.
+    #[allow(unknown_lints, clippy::manual_is_multiple_of)]
+    fn allow_entry(&self, _entry: &impl EventEntry<K, M>) -> bool {
+        let allowed = match self.mode {
+            SampleMode::OneIn(n) => self.state.fetch_add(1, Ordering::Relaxed) % n == 0,
+            SampleMode::Probability(probability) => {
+                if probability >= 1.0 {
+                    true
+                } else if probability <= 0.0 {
+                    false
+                } else {
+                    // Note: Precision loss of the conversion is irrelevant for sampling.
+                    (self.next_random() as f64) < probability * u64::MAX as f64
+                }
+            }
+        };
+
+        if !allowed {
+            self.suppressed.fetch_add(1, Ordering::Relaxed);
+        }
+        allowed
+    }
+}
@@ -5,6 +5,7 @@ use super::Id;
 /// The [`FinalizedEvent`] is returned once a event is captured using [`finalize()`](super::IntermediaryEvent::finalize).
 /// It stores only the event and entry IDs to prevent unnecessarry resource cloning.  
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FinalizedEvent<K: Id> {
     /// The [`Id`] of the associated [`Event`](super::Event).
     pub event_id: K,
@@ -1,6 +1,18 @@
 //! Contains the [`IntermediaryEvent`] trait.
 
-use super::{entry::EventEntry, finalized::FinalizedEvent, origin::Origin, Id, Msg};
+use crate::publisher::{CaptureControl, CaptureOutcome, DispatchError, EvidentPublisher};
+
+use super::{
+    entry::{DefaultEntry, EventEntry},
+    filter::Filter,
+    finalized::FinalizedEvent,
+    origin::Origin,
+    Id, Msg,
+};
+
+/// Derive macro for the [`IntermediaryEvent`] trait.
+#[cfg(feature = "derive")]
+pub use evident_derive::IntermediaryEvent;
 
 /// The [`IntermediaryEvent`] trait is used to add information after setting an event,
 /// and to be able to automatically capture events once they go out of scope.
@@ -21,10 +33,15 @@ where
     fn new(event_id: K, msg: Option<impl Into<M>>, origin: Origin) -> Self;
 
     /// Returns the [`EventEntry`] that was created by this [`IntermediaryEvent`].
+    ///
+    /// **Note:** May panic if the entry was already taken.
     fn get_entry(&self) -> &T;
 
-    /// Takes the [`EventEntry`] that was created by this [`IntermediaryEvent`].
-    fn take_entry(&mut self) -> T;
+    /// Takes the [`EventEntry`] that was created by this [`IntermediaryEvent`],
+    /// or returns `None` if the entry was already taken.
+    ///
+    /// **Note:** The entry is taken once the event is captured, so an event is captured at most once.
+    fn take_entry(&mut self) -> Option<T>;
 
     /// Returns the [`Id`] of this event
     fn get_event_id(&self) -> &K {
@@ -52,3 +69,86 @@ where
         self.finalize().into_event_id()
     }
 }
+
+/// Trait to get the static publisher that captures [`DefaultIntermediaryEvent`]s of an [`Id`].
+///
+/// **Note:** This trait is implemented by the short form of [`create_static_publisher!()`](crate::create_static_publisher),
+/// and should not be implemented manually.
+pub trait DefaultPublisher<M: Msg>: Id + CaptureControl {
+    /// The filter type of the publisher.
+    type Filter: Filter<Self, M> + 'static;
+
+    /// Returns the static publisher that captures [`DefaultIntermediaryEvent`]s with this [`Id`].
+    fn publisher() -> &'static EvidentPublisher<Self, M, DefaultEntry<Self, M>, Self::Filter>;
+}
+
+/// Generic [`IntermediaryEvent`] that creates a [`DefaultEntry`],
+/// and captures it with the publisher of the [`DefaultPublisher`] implementation once it goes out of scope.
+///
+/// **Note:** Use the short form of [`create_static_publisher!()`](crate::create_static_publisher) to create a publisher for this event.
+///
+/// [req:event.entry]
+pub struct DefaultIntermediaryEvent<K, M>
+where
+    K: DefaultPublisher<M>,
+    M: Msg,
+{
+    entry: Option<DefaultEntry<K, M>>,
+}
+
+impl<K, M> IntermediaryEvent<K, M, DefaultEntry<K, M>> for DefaultIntermediaryEvent<K, M>
+where
+    K: DefaultPublisher<M>,
+    M: Msg,
+{
+    fn new(event_id: K, msg: Option<impl Into<M>>, origin: Origin) -> Self {
+        DefaultIntermediaryEvent {
+            entry: Some(DefaultEntry::new(event_id, msg, origin)),
+        }
+    }
+
+    fn get_entry(&self) -> &DefaultEntry<K, M> {
+        self.entry
+            .as_ref()
+            .expect("Entry of intermediary event was already taken.")
+    }
+
+    fn take_entry(&mut self) -> Option<DefaultEntry<K, M>> {
+        self.entry.take()
+    }
+}
+
+impl<K, M> Drop for DefaultIntermediaryEvent<K, M>
+where
+    K: DefaultPublisher<M>,
+    M: Msg,
+{
+    fn drop(&mut self) {
+        K::publisher()._capture(self);
+    }
+}
+
+// Note: Re-impl `finalize()` for better IntelliSense.
+impl<K, M> DefaultIntermediaryEvent<K, M>
+where
+    K: DefaultPublisher<M>,
+    M: Msg,
+{
+    pub fn finalize(self) -> FinalizedEvent<K> {
+        IntermediaryEvent::<K, M, DefaultEntry<K, M>>::finalize(self)
+    }
+
+    /// Finalizes the event, and blocks until it was dispatched to all its subscriptions,
+    /// or the optional timeout was reached.
+    pub fn finalize_and_wait(
+        self,
+        timeout: Option<std::time::Duration>,
+    ) -> Result<FinalizedEvent<K>, DispatchError> {
+        K::publisher().finalize_and_wait(self, timeout)
+    }
+
+    /// Finalizes the event, and returns the outcome of capturing it.
+    pub fn finalize_checked(self) -> CaptureOutcome {
+        K::publisher().finalize_checked(self)
+    }
+}
@@ -2,7 +2,11 @@
 //!
 //! [req:event]
 
-use std::marker::PhantomData;
+use std::{
+    cell::Cell,
+    marker::PhantomData,
+    sync::atomic::{AtomicU64, Ordering},
+};
 
 use self::{entry::EventEntry, intermediary::IntermediaryEvent, origin::Origin};
 
@@ -11,6 +15,11 @@ pub mod filter;
 pub mod finalized;
 pub mod intermediary;
 pub mod origin;
+pub mod record;
+
+/// Derive macro for the [`Id`] trait.
+#[cfg(feature = "derive")]
+pub use evident_derive::Id;
 
 /// Trait that must be implemented for a custom *evident* ID.\
 /// This implementation must then be used for implementations of the traits [`EventEntry`] and [`IntermediaryEvent`].\
@@ -24,6 +33,19 @@ pub trait Id:
 {
 }
 
+/// Optional trait for [`Id`]s that form a hierarchy (e.g. component, sub-component, code).
+///
+/// Implementing this trait allows to subscribe to an event-ID, and all its descendants
+/// using [`subscribe_prefix`](crate::publisher::EvidentPublisher::subscribe_prefix).
+///
+/// [req:event.id]
+pub trait HierarchicalId: Id {
+    /// Returns the parent of this [`Id`], or `None` if this [`Id`] is at the top of the hierarchy.
+    ///
+    /// **Note:** Repeatedly getting the parent must eventually return `None`.
+    fn parent(&self) -> Option<Self>;
+}
+
 /// Trait that must be implemented for a custom event message.\
 /// This implementation must then be used for implementations of the traits [`EventEntry`] and [`IntermediaryEvent`].\
 /// All implementations are needed to create an *evident* publisher using the [`create_static_publisher!()`](crate::create_static_publisher) macro.
@@ -93,12 +115,46 @@ where
 
     /// The [`ThreadId`](std::thread::ThreadId) of the thread the event was set in.
     thread_id: std::thread::ThreadId,
+    /// The number *evident* assigned to the thread the event was set in.
+    pub(crate) thread_nr: u64,
     /// The name of the thread the event was set in if a name exists.
     /// Otherwise: `None`
     thread_name: Option<String>,
 
     /// The [`SystemTime`](std::time::SystemTime) when the event was set.
+    ///
+    /// **Note:** For coalesced events, this is the timestamp of the first repetition.
     pub(crate) timestamp: Option<std::time::SystemTime>,
+
+    /// Number of repetitions of this event that were coalesced into this one.
+    pub(crate) repetitions: usize,
+
+    /// The [`SystemTime`](std::time::SystemTime) of the last repetition, or `None` if the event was not coalesced.
+    pub(crate) last_timestamp: Option<std::time::SystemTime>,
+
+    /// The event-ID that was disabled or enabled, if this event signals a change of disabled IDs.
+    /// Otherwise: `None`
+    pub(crate) signaled_id: Option<K>,
+}
+
+/// Counter for the numbers *evident* assigns to threads.
+static NEXT_THREAD_NR: AtomicU64 = AtomicU64::new(1);
+
+thread_local! {
+    /// Number assigned to the current thread, or `0` if no number was assigned yet.
+    static THREAD_NR: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Returns the number assigned to the current thread, and assigns a new number if none was assigned yet.
+///
+/// **Note:** `ThreadId::as_u64()` is not stable yet, so *evident* assigns its own numbers.
+fn current_thread_nr() -> u64 {
+    THREAD_NR.with(|thread_nr| {
+        if thread_nr.get() == 0 {
+            thread_nr.set(NEXT_THREAD_NR.fetch_add(1, Ordering::Relaxed));
+        }
+        thread_nr.get()
+    })
 }
 
 impl<K: Id, M: Msg, T: EventEntry<K, M>> Event<K, M, T> {
@@ -112,9 +168,13 @@ impl<K: Id, M: Msg, T: EventEntry<K, M>> Event<K, M, T> {
             phantom_m: PhantomData,
 
             thread_id: curr_thread.id(),
+            thread_nr: current_thread_nr(),
             thread_name: curr_thread.name().map(|s| s.to_string()),
 
             timestamp: None,
+            repetitions: 1,
+            last_timestamp: None,
+            signaled_id: None,
         }
     }
 
@@ -159,15 +219,52 @@ impl<K: Id, M: Msg, T: EventEntry<K, M>> Event<K, M, T> {
         &self.thread_id
     }
 
+    /// Get the number *evident* assigned to the thread the event was set in.
+    ///
+    /// **Note:** Thread numbers are assigned in the order threads set their first event,
+    /// and are only unique inside the process the event was set in.
+    pub fn get_thread_nr(&self) -> u64 {
+        self.thread_nr
+    }
+
     /// Get the name of the thread the event was set in.
     pub fn get_thread_name(&self) -> Option<&str> {
         self.thread_name.as_deref()
     }
 
     /// Get the [`SystemTime`](std::time::SystemTime) timestamp of the event.
+    ///
+    /// **Note:** For coalesced events, this is the timestamp of the first repetition.
     pub fn get_timestamp(&self) -> &Option<std::time::SystemTime> {
         &self.timestamp
     }
+
+    /// Get the number of repetitions that were coalesced into this event.
+    ///
+    /// **Note:** Events that were not coalesced have one repetition.
+    pub fn get_repetitions(&self) -> usize {
+        self.repetitions
+    }
+
+    /// Get the [`SystemTime`](std::time::SystemTime) timestamp of the last repetition of the event.
+    ///
+    /// **Note:** Same as [`get_timestamp`](Self::get_timestamp) for events that were not coalesced.
+    pub fn get_last_timestamp(&self) -> &Option<std::time::SystemTime> {
+        match self.last_timestamp {
+            Some(_) => &self.last_timestamp,
+            None => &self.timestamp,
+        }
+    }
+
+    /// Get the event-ID that was disabled or enabled, if this event was sent by
+    /// [`disable_id`](crate::publisher::EvidentPublisher::disable_id) or [`enable_id`](crate::publisher::EvidentPublisher::enable_id).
+    ///
+    /// **Note:** `None` for all events that are no signal events.
+    ///
+    /// [req:cap.ctrl]
+    pub fn get_signaled_id(&self) -> Option<&K> {
+        self.signaled_id.as_ref()
+    }
 }
 
 impl<K: Id, M: Msg, T: EventEntry<K, M>> core::fmt::Debug for Event<K, M, T> {
@@ -176,6 +273,7 @@ impl<K: Id, M: Msg, T: EventEntry<K, M>> core::fmt::Debug for Event<K, M, T> {
             .field("id", &self.entry.get_event_id())
             .field("entry_id", &self.entry.get_entry_id())
             .field("origin", &self.entry.get_origin())
+            .field("repetitions", &self.repetitions)
             .finish()
     }
 }
@@ -1,10 +1,23 @@
 //! Contains the [`Origin`] structure used to know where the event was set.
 
+#[cfg(any(feature = "journal", feature = "log"))]
+use std::{collections::HashSet, sync::Mutex};
+
+#[cfg(any(feature = "journal", feature = "log"))]
+use once_cell::sync::Lazy;
+
+/// Module paths and filenames that were leaked to create [`Origin`]s from [`OwnedOrigin`]s.
+#[cfg(any(feature = "journal", feature = "log"))]
+static LEAKED_STRS: Lazy<Mutex<HashSet<&'static str>>> = Lazy::new(|| Mutex::new(HashSet::new()));
+
 /// Structure to point to a location in the program code.
 /// It is used to know where the event was set, but may be used for other use cases aswell.
 ///
+/// **Note:** Use [`OwnedOrigin`] to deserialize an origin.
+///
 /// [req:event.origin]
 #[derive(Debug, Default, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Origin {
     /// Module path to the code location.
     ///
@@ -57,6 +70,106 @@ impl core::fmt::Display for Origin {
     }
 }
 
+/// Owned counterpart of [`Origin`] that may be created at runtime, or deserialized.
+///
+/// [req:event.origin]
+#[derive(Debug, Default, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OwnedOrigin {
+    /// Module path to the code location.
+    pub module_path: String,
+
+    /// Filename where the code is located.
+    pub filename: String,
+
+    /// Line number where the code is located.
+    pub line_nr: u32,
+}
+
+impl OwnedOrigin {
+    /// Create a new [`OwnedOrigin`].
+    ///
+    /// # Arguments
+    ///
+    /// * `module_path` ... Module path to the code location
+    /// * `filename` ... Filename where the code is located
+    /// * `line_nr` ... Line number where the code is located
+    ///
+    /// [req:event.origin]
+    pub fn new(module_path: impl Into<String>, filename: impl Into<String>, line_nr: u32) -> Self {
+        OwnedOrigin {
+            module_path: module_path.into(),
+            filename: filename.into(),
+            line_nr,
+        }
+    }
+}
+
+impl OwnedOrigin {
+    /// Creates an [`Origin`] from this [`OwnedOrigin`] (e.g. for log records, or events read from a journal).
+    ///
+    /// **Note:** Every distinct module path and filename is leaked once per process,
+    /// because an [`Origin`] only holds `&'static str` fields.
+    #[cfg(any(feature = "journal", feature = "log"))]
+    pub(crate) fn leak(&self) -> Origin {
+        let mut leaked_strs = match LEAKED_STRS.lock() {
+            Ok(leaked_strs) => leaked_strs,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        let mut leak_str = |s: &str| -> &'static str {
+            match leaked_strs.get(s) {
+                Some(leaked) => leaked,
+                None => {
+                    let leaked: &'static str = Box::leak(s.to_string().into_boxed_str());
+                    leaked_strs.insert(leaked);
+                    leaked
+                }
+            }
+        };
+
+        let module_path = leak_str(&self.module_path);
+        let filename = leak_str(&self.filename);
+        Origin::new(module_path, filename, self.line_nr)
+    }
+}
+
+impl From<&Origin> for OwnedOrigin {
+    fn from(origin: &Origin) -> Self {
+        OwnedOrigin::new(origin.module_path, origin.filename, origin.line_nr)
+    }
+}
+
+impl From<Origin> for OwnedOrigin {
+    fn from(origin: Origin) -> Self {
+        OwnedOrigin::from(&origin)
+    }
+}
+
+impl PartialEq<Origin> for OwnedOrigin {
+    fn eq(&self, other: &Origin) -> bool {
+        self.module_path == other.module_path
+            && self.filename == other.filename
+            && self.line_nr == other.line_nr
+    }
+}
+
+impl From<&OwnedOrigin> for String {
+    /// Formats given [`OwnedOrigin`] as `module="<module path>", file="<filename>", line=<line number>`.
+    fn from(origin: &OwnedOrigin) -> Self {
+        format!(
+            "module=\"{}\", file=\"{}\", line={}",
+            origin.module_path, origin.filename, origin.line_nr
+        )
+    }
+}
+
+impl core::fmt::Display for OwnedOrigin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", String::from(self))
+    }
+}
+
 /// Convenience wrapper to create an [`Origin`] for the code position this macro is used at.
 ///
 /// [req:event.origin], [req:qa.ux.macros]
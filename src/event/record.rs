@@ -0,0 +1,118 @@
+//! Contains the [`EventRecord`] struct that is an owned form of an [`Event`].
+
+use super::{
+    entry::EventEntry,
+    origin::{Origin, OwnedOrigin},
+    Event, Id, Msg,
+};
+
+/// Owned form of an [`Event`] that keeps all event information, but is independent of the [`EventEntry`] type.
+///
+/// Records may be used to store, ship, or compare events outside of the process they were set in.
+///
+/// **Note:** With feature `serde`, records may be serialized and deserialized.
+///
+/// [req:event]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EventRecord<K: Id, M: Msg> {
+    /// The [`Id`] of the event.
+    pub event_id: K,
+
+    /// The ID of the [`EventEntry`] of the event.
+    pub entry_id: crate::uuid::Uuid,
+
+    /// The main message of the event, or `None` if no message was given.
+    pub msg: Option<M>,
+
+    /// The origin the event was set at.
+    pub origin: OwnedOrigin,
+
+    /// The number *evident* assigned to the thread the event was set in.
+    ///
+    /// **Note:** Thread numbers are only unique inside the process the event was set in.
+    pub thread_nr: u64,
+
+    /// The name of the thread the event was set in if a name exists.
+    pub thread_name: Option<String>,
+
+    /// The [`SystemTime`](std::time::SystemTime) when the event was set.
+    pub timestamp: Option<std::time::SystemTime>,
+
+    /// Number of repetitions that were coalesced into the event.
+    #[cfg_attr(feature = "serde", serde(default = "single_repetition"))]
+    pub repetitions: usize,
+
+    /// The [`SystemTime`](std::time::SystemTime) of the last repetition, or `None` if the event was not coalesced.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub last_timestamp: Option<std::time::SystemTime>,
+
+    /// The event-ID that was disabled or enabled, if the event signals a change of disabled IDs.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub signaled_id: Option<K>,
+}
+
+impl<K: Id, M: Msg, T: EventEntry<K, M>> From<&Event<K, M, T>> for EventRecord<K, M> {
+    fn from(event: &Event<K, M, T>) -> Self {
+        EventRecord {
+            event_id: event.get_event_id().clone(),
+            entry_id: event.get_entry_id(),
+            msg: event.get_msg().cloned(),
+            origin: OwnedOrigin::from(event.get_origin()),
+            thread_nr: event.get_thread_nr(),
+            thread_name: event.get_thread_name().map(|name| name.to_string()),
+            timestamp: *event.get_timestamp(),
+            repetitions: event.repetitions,
+            last_timestamp: event.last_timestamp,
+            signaled_id: event.signaled_id.clone(),
+        }
+    }
+}
+
+impl<K: Id, M: Msg> EventRecord<K, M> {
+    /// Converts this record into an [`Event`] with the given [`Origin`].
+    ///
+    /// The entry-ID, message, thread number, thread name, and timestamp of the record are kept.
+    ///
+    /// **Warning:** The entry is created with [`EventEntry::with_entry_id`].
+    /// The entry-ID is only kept if the entry type overrides this function.
+    ///
+    /// **Note:** The origin must be given separately, because an [`Origin`] only holds `&'static str` fields.
+    /// The [`ThreadId`](std::thread::ThreadId) of the current thread is used, because thread IDs cannot be created.
+    ///
+    /// [req:event]
+    pub fn into_event<T: EventEntry<K, M>>(self, origin: Origin) -> Event<K, M, T> {
+        let mut event = Event::new(T::with_entry_id(
+            self.event_id,
+            self.entry_id,
+            self.msg,
+            origin,
+        ));
+        event.thread_nr = self.thread_nr;
+        event.thread_name = self.thread_name;
+        event.timestamp = self.timestamp;
+        event.repetitions = self.repetitions;
+        event.last_timestamp = self.last_timestamp;
+        event.signaled_id = self.signaled_id;
+        event
+    }
+}
+
+/// Default number of repetitions for records without repetition information.
+#[cfg(feature = "serde")]
+fn single_repetition() -> usize {
+    1
+}
+
+/// Events are serialized in their [`EventRecord`] form.
+#[cfg(feature = "serde")]
+impl<K, M, T> serde::Serialize for Event<K, M, T>
+where
+    K: Id + serde::Serialize,
+    M: Msg + serde::Serialize,
+    T: EventEntry<K, M>,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        EventRecord::from(self).serialize(serializer)
+    }
+}
@@ -0,0 +1,471 @@
+//! Contains the [`JournalSink`] that writes captured events into an append-only journal,
+//! and the [`JournalReader`] to read events from a journal.
+//!
+//! A journal starts with a header consisting of [`JOURNAL_MAGIC`], followed by the journal version as little-endian `u16`.
+//! Every event is appended as JSON encoded [`EventRecord`], prefixed by its length in bytes as little-endian `u32`.
+//!
+//! **Note:** Replayed events only keep their entry-IDs if the entry type overrides [`EventEntry::with_entry_id`].
+//! With the default implementation, replayed events get new entry-IDs.
+//!
+//! [req:subs.all]
+
+use std::{
+    fs::{File, OpenOptions},
+    io::{BufRead, BufReader, BufWriter, Read, Seek, SeekFrom, Write},
+    marker::PhantomData,
+    path::Path,
+    sync::{atomic::AtomicBool, Arc},
+};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    channel::Receiver,
+    event::{entry::EventEntry, filter::Filter, record::EventRecord, Event, Id, Msg},
+    publisher::{CaptureControl, EvidentPublisher},
+    worker::{consume_events, EventWorker},
+};
+
+/// Magic bytes at the start of every journal.
+pub const JOURNAL_MAGIC: &[u8; 8] = b"EVIDENTJ";
+
+/// The journal version written by [`JournalWriter`]s, and supported by [`JournalReader`]s.
+pub const JOURNAL_VERSION: u16 = 1;
+
+/// Length in bytes of the journal header.
+const HEADER_LEN: u64 = (JOURNAL_MAGIC.len() + 2) as u64;
+
+/// Maximum length in bytes of one encoded event record.
+///
+/// **Note:** Larger length prefixes are treated as corrupted journal, so a damaged prefix cannot cause huge allocations.
+pub const MAX_RECORD_LEN: u32 = 16 * 1024 * 1024;
+
+/// Possible errors when writing or reading a journal.
+#[derive(Debug)]
+pub enum JournalError {
+    /// Accessing the journal file failed.
+    Io(std::io::Error),
+
+    /// The journal does not start with [`JOURNAL_MAGIC`].
+    InvalidHeader,
+
+    /// The journal was written with an unsupported version.
+    ///
+    /// The version of the journal may be accessed at tuple position 0.
+    UnsupportedVersion(u16),
+
+    /// An event record could not be serialized or deserialized.
+    Serialization(serde_json::Error),
+
+    /// The journal ends in the middle of an event record.
+    ///
+    /// **Note:** This may happen if the program writing the journal was aborted.
+    TruncatedRecord,
+
+    /// The length of an event record exceeds [`MAX_RECORD_LEN`].
+    ///
+    /// The length of the record may be accessed at tuple position 0.
+    RecordTooLarge(u64),
+
+    /// The subscription to all events could not be created.
+    CouldNotSubscribe,
+
+    /// The journal thread of a [`JournalSink`] panicked.
+    SinkPanicked,
+}
+
+impl From<std::io::Error> for JournalError {
+    fn from(err: std::io::Error) -> Self {
+        JournalError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for JournalError {
+    fn from(err: serde_json::Error) -> Self {
+        JournalError::Serialization(err)
+    }
+}
+
+/// Appends event records to a journal file.
+pub struct JournalWriter<K: Id, M: Msg> {
+    writer: BufWriter<File>,
+    records_written: u64,
+
+    // PahmtomData needed for unused generics
+    phantom_k: PhantomData<K>,
+    phantom_m: PhantomData<M>,
+}
+
+impl<K, M> JournalWriter<K, M>
+where
+    K: Id + Serialize,
+    M: Msg + Serialize,
+{
+    /// Opens the journal at the given path to append event records.
+    ///
+    /// A new journal is created if the file does not exist, is empty, or only contains the start of a journal header.
+    /// A torn record at the end of an existing journal is truncated, so new records are appended after the last complete record.
+    ///
+    /// # Possible Errors
+    ///
+    /// * [`JournalError::InvalidHeader`] ... If the existing file is not a journal
+    /// * [`JournalError::UnsupportedVersion`] ... If the existing journal was written with another version
+    /// * [`JournalError::RecordTooLarge`] ... If a record of the existing journal exceeds [`MAX_RECORD_LEN`]
+    /// * [`JournalError::Io`] ... If the file could not be opened or written
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, JournalError> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .read(true)
+            .append(true)
+            .open(path)?;
+
+        let file_len = file.metadata()?.len();
+        if file_len > 0 && file_len < HEADER_LEN && is_torn_header(&mut file)? {
+            // Note: A torn header is left if the program was aborted while the journal was created.
+            file.set_len(0)?;
+        }
+
+        if file.metadata()?.len() == 0 {
+            file.write_all(&journal_header())?;
+            file.flush()?;
+        } else {
+            // Note: Reads start at the beginning of the file, even in append mode.
+            read_header(&mut file)?;
+            truncate_torn_record(&mut file)?;
+        }
+
+        Ok(JournalWriter {
+            writer: BufWriter::new(file),
+            records_written: 0,
+            phantom_k: PhantomData,
+            phantom_m: PhantomData,
+        })
+    }
+
+    /// Appends the given event record to the journal.
+    ///
+    /// **Note:** Records are buffered. Use [`flush`](Self::flush) to ensure they are written to the file.
+    pub fn append(&mut self, record: &EventRecord<K, M>) -> Result<(), JournalError> {
+        let encoded = serde_json::to_vec(record)?;
+        let len = match u32::try_from(encoded.len()) {
+            Ok(len) if len <= MAX_RECORD_LEN => len,
+            _ => {
+                return Err(JournalError::RecordTooLarge(encoded.len() as u64));
+            }
+        };
+
+        self.writer.write_all(&len.to_le_bytes())?;
+        self.writer.write_all(&encoded)?;
+        self.records_written += 1;
+
+        Ok(())
+    }
+
+    /// Writes all buffered records to the journal file.
+    pub fn flush(&mut self) -> Result<(), JournalError> {
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    /// Returns the number of records appended by this writer.
+    pub fn records_written(&self) -> u64 {
+        self.records_written
+    }
+}
+
+/// Subscriber that writes every captured event of a publisher into an append-only journal.
+///
+/// Events are written by a separate thread, and flushed to the journal file
+/// once no more events are waiting in the subscription channel.
+///
+/// The journal thread stops once [`stop`](Self::stop) is called, the sink is dropped, or the publisher is shut down.
+pub struct JournalSink {
+    worker: EventWorker<Result<u64, JournalError>>,
+}
+
+impl JournalSink {
+    /// Subscribes to all events of the given static publisher, and writes them into the journal at the given path.
+    ///
+    /// See [`JournalWriter::open`] for possible errors when opening the journal.
+    pub fn spawn<K, M, T, F>(
+        publisher: &'static EvidentPublisher<K, M, T, F>,
+        path: impl AsRef<Path>,
+    ) -> Result<Self, JournalError>
+    where
+        K: Id + CaptureControl + Serialize,
+        M: Msg + Serialize,
+        T: EventEntry<K, M>,
+        F: Filter<K, M> + Send + Sync,
+    {
+        let writer = JournalWriter::open(path)?;
+        let subscription = publisher
+            .subscribe_to_all_events()
+            .map_err(|_| JournalError::CouldNotSubscribe)?;
+
+        Ok(JournalSink {
+            worker: EventWorker::spawn(move |stop: &AtomicBool| {
+                write_events(subscription.get_receiver(), writer, stop)
+            }),
+        })
+    }
+
+    /// Subscribes to all events of the given shared publisher, and writes them into the journal at the given path.
+    ///
+    /// See [`JournalWriter::open`] for possible errors when opening the journal.
+    pub fn spawn_shared<K, M, T, F>(
+        publisher: &Arc<EvidentPublisher<K, M, T, F>>,
+        path: impl AsRef<Path>,
+    ) -> Result<Self, JournalError>
+    where
+        K: Id + CaptureControl + Serialize,
+        M: Msg + Serialize,
+        T: EventEntry<K, M>,
+        F: Filter<K, M> + Send + Sync + 'static,
+    {
+        let writer = JournalWriter::open(path)?;
+        let subscription = publisher
+            .subscribe_to_all_events_owned()
+            .map_err(|_| JournalError::CouldNotSubscribe)?;
+
+        Ok(JournalSink {
+            worker: EventWorker::spawn(move |stop: &AtomicBool| {
+                write_events(subscription.get_receiver(), writer, stop)
+            }),
+        })
+    }
+
+    /// Stops the journal thread once all events in the subscription channel are written,
+    /// and returns the number of written records.
+    ///
+    /// **Note:** Use `flush()` of the publisher beforehand to make sure all captured events reached the subscription channel.
+    pub fn stop(self) -> Result<u64, JournalError> {
+        self.worker.stop().ok_or(JournalError::SinkPanicked)?
+    }
+}
+
+/// Writes events of the given receiver into the journal, until `stop` is set or the channel is disconnected.
+///
+/// Returns the number of written records.
+fn write_events<K, M, T>(
+    receiver: &Receiver<Arc<Event<K, M, T>>>,
+    mut writer: JournalWriter<K, M>,
+    stop: &AtomicBool,
+) -> Result<u64, JournalError>
+where
+    K: Id + Serialize,
+    M: Msg + Serialize,
+    T: EventEntry<K, M>,
+{
+    // Note: Flushing once no more events are waiting reduces the number of writes to the file.
+    consume_events(
+        receiver,
+        stop,
+        &mut writer,
+        |writer, event| writer.append(&EventRecord::from(&*event)),
+        JournalWriter::flush,
+    )?;
+    writer.flush()?;
+
+    Ok(writer.records_written())
+}
+
+/// Reads event records from a journal.
+///
+/// Records are returned in the order they were written by iterating over the reader.
+/// The iteration ends after the first error.
+pub struct JournalReader<K: Id, M: Msg> {
+    reader: BufReader<File>,
+    failed: bool,
+
+    // PahmtomData needed for unused generics
+    phantom_k: PhantomData<K>,
+    phantom_m: PhantomData<M>,
+}
+
+impl<K, M> JournalReader<K, M>
+where
+    K: Id + DeserializeOwned,
+    M: Msg + DeserializeOwned,
+{
+    /// Opens the journal at the given path.
+    ///
+    /// # Possible Errors
+    ///
+    /// * [`JournalError::InvalidHeader`] ... If the file is not a journal
+    /// * [`JournalError::UnsupportedVersion`] ... If the journal was written with another version
+    /// * [`JournalError::Io`] ... If the file could not be opened or read
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, JournalError> {
+        let mut reader = BufReader::new(File::open(path)?);
+        read_header(&mut reader)?;
+
+        Ok(JournalReader {
+            reader,
+            failed: false,
+            phantom_k: PhantomData,
+            phantom_m: PhantomData,
+        })
+    }
+
+    /// Re-injects all remaining events of the journal into the given publisher,
+    /// so subscribers receive them as if they were captured live.
+    ///
+    /// Returns the number of injected events.
+    ///
+    /// **Note:** Every distinct module path and filename is leaked once per process,
+    /// because an [`Origin`](crate::event::origin::Origin) only holds `&'static str` fields.
+    ///
+    /// See [`inject_event`](EvidentPublisher::inject_event) and [`EventRecord::into_event`] for more information.
+    pub fn inject_into<T, F>(
+        self,
+        publisher: &EvidentPublisher<K, M, T, F>,
+    ) -> Result<u64, JournalError>
+    where
+        K: CaptureControl,
+        T: EventEntry<K, M>,
+        F: Filter<K, M>,
+    {
+        let mut injected = 0;
+
+        for record in self {
+            let record = record?;
+            let origin = record.origin.leak();
+
+            if !publisher.inject_event(record.into_event(origin)) {
+                break;
+            }
+            injected += 1;
+        }
+
+        Ok(injected)
+    }
+
+    /// Reads the next event record, or returns `None` if the journal ends after the previous record.
+    fn read_record(&mut self) -> Option<Result<EventRecord<K, M>, JournalError>> {
+        match self.reader.fill_buf() {
+            Ok([]) => {
+                return None;
+            }
+            Ok(_) => {}
+            Err(err) => {
+                return Some(Err(err.into()));
+            }
+        }
+
+        let mut len_bytes = [0; 4];
+        if let Err(err) = self.reader.read_exact(&mut len_bytes) {
+            return Some(Err(map_eof(err)));
+        }
+
+        let len = u32::from_le_bytes(len_bytes);
+        if len > MAX_RECORD_LEN {
+            return Some(Err(JournalError::RecordTooLarge(len.into())));
+        }
+
+        let mut encoded = vec![0; len as usize];
+        if let Err(err) = self.reader.read_exact(&mut encoded) {
+            return Some(Err(map_eof(err)));
+        }
+
+        Some(serde_json::from_slice(&encoded).map_err(JournalError::from))
+    }
+}
+
+impl<K, M> Iterator for JournalReader<K, M>
+where
+    K: Id + DeserializeOwned,
+    M: Msg + DeserializeOwned,
+{
+    type Item = Result<EventRecord<K, M>, JournalError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        let record = self.read_record();
+        if let Some(Err(_)) = record {
+            self.failed = true;
+        }
+        record
+    }
+}
+
+/// Returns the header written at the start of every journal.
+fn journal_header() -> Vec<u8> {
+    let mut header = JOURNAL_MAGIC.to_vec();
+    header.extend_from_slice(&JOURNAL_VERSION.to_le_bytes());
+    header
+}
+
+/// Returns `true` if the given file only contains the start of a journal header.
+///
+/// **Note:** The file must be shorter than the header.
+fn is_torn_header(file: &mut File) -> Result<bool, JournalError> {
+    let mut content = Vec::new();
+    file.read_to_end(&mut content)?;
+    file.seek(SeekFrom::Start(0))?;
+
+    Ok(journal_header().starts_with(&content))
+}
+
+/// Reads and validates the journal header.
+fn read_header(reader: &mut impl Read) -> Result<(), JournalError> {
+    let mut magic = [0; JOURNAL_MAGIC.len()];
+    reader
+        .read_exact(&mut magic)
+        .map_err(|err| match err.kind() {
+            std::io::ErrorKind::UnexpectedEof => JournalError::InvalidHeader,
+            _ => JournalError::Io(err),
+        })?;
+    if &magic != JOURNAL_MAGIC {
+        return Err(JournalError::InvalidHeader);
+    }
+
+    let mut version = [0; 2];
+    reader.read_exact(&mut version).map_err(map_eof)?;
+    let version = u16::from_le_bytes(version);
+    if version != JOURNAL_VERSION {
+        return Err(JournalError::UnsupportedVersion(version));
+    }
+
+    Ok(())
+}
+
+/// Truncates a torn record at the end of the journal, after the header was read from the given file.
+///
+/// **Note:** Only the length prefixes of records are read, because complete records are not modified.
+fn truncate_torn_record(file: &mut File) -> Result<(), JournalError> {
+    let file_len = file.metadata()?.len();
+    let mut reader = BufReader::new(&mut *file);
+    let mut record_end = HEADER_LEN;
+
+    while record_end + 4 <= file_len {
+        let mut len_bytes = [0; 4];
+        reader.read_exact(&mut len_bytes)?;
+
+        let len = u32::from_le_bytes(len_bytes);
+        if len > MAX_RECORD_LEN {
+            return Err(JournalError::RecordTooLarge(len.into()));
+        }
+
+        let next_end = record_end + 4 + u64::from(len);
+        if next_end > file_len {
+            break;
+        }
+        reader.seek(SeekFrom::Start(next_end))?;
+        record_end = next_end;
+    }
+
+    if record_end < file_len {
+        file.set_len(record_end)?;
+    }
+    Ok(())
+}
+
+/// Maps an unexpected end of the journal to [`JournalError::TruncatedRecord`].
+fn map_eof(err: std::io::Error) -> JournalError {
+    match err.kind() {
+        std::io::ErrorKind::UnexpectedEof => JournalError::TruncatedRecord,
+        _ => JournalError::Io(err),
+    }
+}
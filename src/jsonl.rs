@@ -0,0 +1,262 @@
+//! Contains the [`JsonLinesWriter`] that writes received events as JSON Lines.
+//!
+//! Every event is written as one JSON object per line in the form:
+//!
+//! ```json
+//! {"id":<event-ID>,"entry_id":"<uuid>","msg":<msg or null>,"origin":{"module":"<module path>","file":"<filename>","line":<line number>},"thread_nr":<number>,"thread_name":<name or null>,"timestamp":"<RFC 3339 or null>"}
+//! ```
+//!
+//! [req:subs]
+
+use std::{
+    io::{BufWriter, Write},
+    sync::{atomic::AtomicBool, Arc},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use serde::Serialize;
+
+use crate::{
+    channel::Receiver,
+    event::{entry::EventEntry, filter::Filter, Event, Id, Msg},
+    publisher::CaptureControl,
+    subscription::{OwnedSubscription, Subscription},
+    worker::{consume_events, EventWorker},
+};
+
+/// Configuration of a [`JsonLinesWriter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JsonLinesConfig {
+    /// Capacity in bytes of the buffer that lines are written to before they are written to the output.
+    pub buffer_capacity: usize,
+
+    /// Maximum duration buffered lines are kept before they are flushed to the output.
+    pub flush_interval: Duration,
+}
+
+impl Default for JsonLinesConfig {
+    /// Uses a buffer capacity of 8 KiB, and a flush interval of 1 second.
+    fn default() -> Self {
+        JsonLinesConfig {
+            buffer_capacity: 8 * 1024,
+            flush_interval: Duration::from_secs(1),
+        }
+    }
+}
+
+/// Consumer that writes every event received by a subscription as JSON Lines to an output.
+///
+/// Events are written by a separate thread that takes ownership of the subscription and the output.
+/// The writer thread stops once [`stop`](Self::stop) is called, the writer is dropped, or the publisher is shut down.
+pub struct JsonLinesWriter {
+    worker: EventWorker<std::io::Result<u64>>,
+}
+
+impl JsonLinesWriter {
+    /// Writes all events received by the given subscription of a static publisher to the given output.
+    pub fn spawn<K, M, T, F>(
+        subscription: Subscription<'static, K, M, T, F>,
+        output: impl Write + Send + 'static,
+        config: JsonLinesConfig,
+    ) -> Self
+    where
+        K: Id + CaptureControl + Serialize,
+        M: Msg + Serialize,
+        T: EventEntry<K, M>,
+        F: Filter<K, M> + Send + Sync,
+    {
+        JsonLinesWriter {
+            worker: EventWorker::spawn(move |stop: &AtomicBool| {
+                write_lines(subscription.get_receiver(), output, config, stop)
+            }),
+        }
+    }
+
+    /// Writes all events received by the given owned subscription to the given output.
+    pub fn spawn_owned<K, M, T, F>(
+        subscription: OwnedSubscription<K, M, T, F>,
+        output: impl Write + Send + 'static,
+        config: JsonLinesConfig,
+    ) -> Self
+    where
+        K: Id + CaptureControl + Serialize,
+        M: Msg + Serialize,
+        T: EventEntry<K, M>,
+        F: Filter<K, M> + Send + Sync + 'static,
+    {
+        JsonLinesWriter {
+            worker: EventWorker::spawn(move |stop: &AtomicBool| {
+                write_lines(subscription.get_receiver(), output, config, stop)
+            }),
+        }
+    }
+
+    /// Stops the writer thread once all events in the subscription channel are written and flushed,
+    /// and returns the number of written lines.
+    ///
+    /// **Note:** Use `flush()` of the publisher beforehand to make sure all captured events reached the subscription channel.
+    pub fn stop(self) -> std::io::Result<u64> {
+        self.worker
+            .stop()
+            .ok_or_else(|| std::io::Error::other("JSON Lines writer panicked"))?
+    }
+}
+
+/// Writes events of the given receiver as JSON Lines, until `stop` is set or the channel is disconnected.
+///
+/// Returns the number of written lines.
+fn write_lines<K, M, T>(
+    receiver: &Receiver<Arc<Event<K, M, T>>>,
+    output: impl Write,
+    config: JsonLinesConfig,
+    stop: &AtomicBool,
+) -> std::io::Result<u64>
+where
+    K: Id + Serialize,
+    M: Msg + Serialize,
+    T: EventEntry<K, M>,
+{
+    let mut lines = BufferedLines {
+        writer: BufWriter::with_capacity(config.buffer_capacity, output),
+        written_lines: 0,
+        flush_interval: config.flush_interval,
+        last_flush: Instant::now(),
+    };
+
+    consume_events(
+        receiver,
+        stop,
+        &mut lines,
+        |lines, event| {
+            write_line(&mut lines.writer, &event)?;
+            lines.written_lines += 1;
+            lines.flush_if_due()
+        },
+        BufferedLines::flush_if_due,
+    )?;
+    lines.writer.flush()?;
+
+    Ok(lines.written_lines)
+}
+
+/// Buffered output of a [`JsonLinesWriter`], that is flushed once the flush interval elapsed.
+struct BufferedLines<W: Write> {
+    writer: BufWriter<W>,
+    written_lines: u64,
+    flush_interval: Duration,
+    last_flush: Instant,
+}
+
+impl<W: Write> BufferedLines<W> {
+    fn flush_if_due(&mut self) -> std::io::Result<()> {
+        if self.last_flush.elapsed() >= self.flush_interval {
+            self.writer.flush()?;
+            self.last_flush = Instant::now();
+        }
+        Ok(())
+    }
+}
+
+/// Writes the given event as one JSON object followed by a newline.
+pub(crate) fn write_line<K, M, T>(
+    writer: &mut impl Write,
+    event: &Event<K, M, T>,
+) -> std::io::Result<()>
+where
+    K: Id + Serialize,
+    M: Msg + Serialize,
+    T: EventEntry<K, M>,
+{
+    serde_json::to_writer(&mut *writer, &JsonLine::from(event))?;
+    writer.write_all(b"\n")
+}
+
+/// Form of an [`Event`] that is written as one line.
+#[derive(Serialize)]
+struct JsonLine<'a, K: Id, M: Msg> {
+    id: &'a K,
+    entry_id: crate::uuid::Uuid,
+    msg: Option<&'a M>,
+    origin: JsonOrigin<'a>,
+    thread_nr: u64,
+    thread_name: Option<&'a str>,
+    timestamp: Option<String>,
+}
+
+#[derive(Serialize)]
+struct JsonOrigin<'a> {
+    module: &'a str,
+    file: &'a str,
+    line: u32,
+}
+
+impl<'a, K: Id, M: Msg, T: EventEntry<K, M>> From<&'a Event<K, M, T>> for JsonLine<'a, K, M> {
+    fn from(event: &'a Event<K, M, T>) -> Self {
+        let origin = event.get_origin();
+
+        JsonLine {
+            id: event.get_event_id(),
+            entry_id: event.get_entry_id(),
+            msg: event.get_msg(),
+            origin: JsonOrigin {
+                module: origin.module_path,
+                file: origin.filename,
+                line: origin.line_nr,
+            },
+            thread_nr: event.get_thread_nr(),
+            thread_name: event.get_thread_name(),
+            timestamp: event.get_timestamp().map(rfc3339),
+        }
+    }
+}
+
+/// Formats the given time as RFC 3339 timestamp in UTC with microsecond precision (e.g. `2023-05-17T08:15:42.123456Z`).
+pub fn rfc3339(time: SystemTime) -> String {
+    let (secs, micros) = match time.duration_since(UNIX_EPOCH) {
+        Ok(since_epoch) => (since_epoch.as_secs() as i64, since_epoch.subsec_micros()),
+        Err(err) => {
+            // Note: Times before the epoch are counted backwards, so fractions must be inverted.
+            let before_epoch = err.duration();
+            let mut secs = -(before_epoch.as_secs() as i64);
+            let mut micros = before_epoch.subsec_micros();
+            if micros > 0 {
+                secs -= 1;
+                micros = 1_000_000 - micros;
+            }
+            (secs, micros)
+        }
+    };
+
+    let days = secs.div_euclid(86_400);
+    let secs_of_day = secs.rem_euclid(86_400);
+    let (year, month, day) = civil_from_days(days);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:06}Z",
+        year,
+        month,
+        day,
+        secs_of_day / 3600,
+        secs_of_day % 3600 / 60,
+        secs_of_day % 60,
+        micros
+    )
+}
+
+/// Converts days since the Unix epoch into a (year, month, day) date of the proleptic Gregorian calendar.
+///
+/// See: <http://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    (year, month, day)
+}
@@ -11,11 +11,43 @@
 //! Checkout the [tests/min_msg](https://github.com/mhatzl/evident/tree/main/tests/min_msg) folder
 //! if you want to send custom event messages instead of regular [`String`]s.
 
+#[cfg(feature = "async")]
+pub mod async_subscription;
+#[cfg(any(feature = "log", feature = "tracing"))]
+mod bridged_event;
+pub mod channel;
 pub mod creation_macros;
 pub mod event;
+#[cfg(feature = "journal")]
+pub mod journal;
+#[cfg(feature = "jsonl")]
+pub mod jsonl;
+#[cfg(feature = "log")]
+pub mod log_bridge;
 pub mod publisher;
+#[cfg(feature = "rotating-file")]
+pub mod rotating_file;
 pub mod subscription;
+#[cfg(feature = "tracing")]
+pub mod tracing_bridge;
+#[cfg(any(
+    feature = "journal",
+    feature = "jsonl",
+    feature = "rotating-file",
+    feature = "tracing"
+))]
+mod worker;
 
 // Re-export external crates used in API
+#[cfg(feature = "async")]
+pub use futures;
+#[cfg(feature = "log")]
+pub use log;
 pub use once_cell;
+#[cfg(feature = "serde")]
+pub use serde;
+#[cfg(feature = "tracing")]
+pub use tracing;
+#[cfg(feature = "tracing")]
+pub use tracing_subscriber;
 pub use uuid;
@@ -0,0 +1,151 @@
+//! Contains the [`EvidentLogger`] that forwards records of the [`log`] crate to an [`EvidentPublisher`].
+//!
+//! [req:cap]
+
+use std::sync::Arc;
+
+use log::{Level, LevelFilter, Log, Metadata, Record};
+
+use crate::{
+    bridged_event::BridgedEvent,
+    event::{
+        entry::EventEntry,
+        filter::Filter,
+        intermediary::IntermediaryEvent,
+        origin::{Origin, OwnedOrigin},
+        Id, Msg,
+    },
+    publisher::{CaptureControl, EvidentPublisher},
+    subscription::PublisherRef,
+};
+
+/// Function to map the target and level of a log record to an event-ID.
+///
+/// Records are ignored if `None` is returned.
+pub type LogMapping<K> = Box<dyn Fn(&str, Level) -> Option<K> + Send + Sync>;
+
+/// [`Log`] implementation that converts log records into events, and captures them with an [`EvidentPublisher`].
+///
+/// The formatted message of a record is used as event message,
+/// and the module path, file, and line of the record are used for the event [`Origin`].
+///
+/// Events are captured like events set with `set_event!()`,
+/// so the filter, capture control, and missed-capture accounting of the publisher apply.
+///
+/// **Note:** Logging inside the event handler of the publisher might block forever in *blocking* capture mode,
+/// because the handler would wait on itself if the capture buffer is full.
+pub struct EvidentLogger<K, M, T, F>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+    T: EventEntry<K, M>,
+    F: Filter<K, M> + 'static,
+{
+    publisher: PublisherRef<'static, K, M, T, F>,
+    mapping: LogMapping<K>,
+    max_level: LevelFilter,
+}
+
+impl<K, M, T, F> EvidentLogger<K, M, T, F>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+    T: EventEntry<K, M>,
+    F: Filter<K, M> + Send + Sync + 'static,
+    String: Into<M>,
+{
+    /// Creates a new [`EvidentLogger`] for the given static publisher.
+    ///
+    /// # Arguments
+    ///
+    /// * `publisher` ... The publisher used to capture log records
+    /// * `mapping` ... Function to map the target and level of a log record to an event-ID, or `None` to ignore the record
+    pub fn new(
+        publisher: &'static EvidentPublisher<K, M, T, F>,
+        mapping: impl Fn(&str, Level) -> Option<K> + Send + Sync + 'static,
+    ) -> Self {
+        EvidentLogger {
+            publisher: PublisherRef::Borrowed(publisher),
+            mapping: Box::new(mapping),
+            max_level: LevelFilter::Trace,
+        }
+    }
+
+    /// Creates a new [`EvidentLogger`] for the given shared publisher.
+    ///
+    /// See [`new`](Self::new) for more information.
+    pub fn new_shared(
+        publisher: Arc<EvidentPublisher<K, M, T, F>>,
+        mapping: impl Fn(&str, Level) -> Option<K> + Send + Sync + 'static,
+    ) -> Self {
+        EvidentLogger {
+            publisher: PublisherRef::Owned(publisher),
+            mapping: Box::new(mapping),
+            max_level: LevelFilter::Trace,
+        }
+    }
+
+    /// Sets the maximum level of log records that are converted into events.
+    ///
+    /// **Note:** All levels are converted per default.
+    pub fn with_max_level(mut self, max_level: LevelFilter) -> Self {
+        self.max_level = max_level;
+        self
+    }
+
+    /// Sets this logger as the global logger of the [`log`] crate, and sets the global maximum log level.
+    ///
+    /// Returns an error if a global logger was already set.
+    pub fn init(self) -> Result<(), log::SetLoggerError> {
+        let max_level = self.max_level;
+        log::set_boxed_logger(Box::new(self))?;
+        log::set_max_level(max_level);
+        Ok(())
+    }
+}
+
+impl<K, M, T, F> Log for EvidentLogger<K, M, T, F>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+    T: EventEntry<K, M>,
+    F: Filter<K, M> + Send + Sync + 'static,
+    String: Into<M>,
+{
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.max_level
+            && (self.mapping)(metadata.target(), metadata.level()).is_some()
+    }
+
+    fn log(&self, record: &Record) {
+        if record.level() > self.max_level {
+            return;
+        }
+
+        let Some(event_id) = (self.mapping)(record.target(), record.level()) else {
+            return;
+        };
+
+        // Note: Origins require `&'static str`, so strings of records created at runtime are copied.
+        let origin = match (record.module_path_static(), record.file_static()) {
+            (Some(module_path), Some(filename)) => {
+                Origin::new(module_path, filename, record.line().unwrap_or_default())
+            }
+            _ => OwnedOrigin::new(
+                record.module_path().unwrap_or_default(),
+                record.file().unwrap_or_default(),
+                record.line().unwrap_or_default(),
+            )
+            .leak(),
+        };
+
+        let mut log_event: BridgedEvent<K, M, T> =
+            BridgedEvent::new(event_id, Some(record.args().to_string()), origin);
+        self.publisher._capture(&mut log_event);
+    }
+
+    /// Blocks until all captured records were forwarded to the event handler.
+    fn flush(&self) {
+        self.publisher.flush();
+    }
+}
@@ -5,18 +5,31 @@
 //! [req:pub]
 
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet, VecDeque},
+    marker::PhantomData,
     sync::{
         atomic::{AtomicBool, AtomicUsize, Ordering},
-        mpsc::{self, SyncSender, TrySendError},
-        Arc, RwLock,
+        mpsc::{self, RecvTimeoutError, SyncSender, TrySendError},
+        Arc, Mutex, RwLock, Weak,
     },
-    thread,
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
 };
 
+use once_cell::sync::OnceCell;
+
+#[cfg(feature = "async")]
+use crate::async_subscription::AsyncSubscription;
 use crate::{
-    event::{entry::EventEntry, filter::Filter, intermediary::IntermediaryEvent, Event, Id, Msg},
-    subscription::{Subscription, SubscriptionError, SubscriptionSender},
+    channel::{channel, Receiver},
+    event::{
+        entry::EventEntry, filter::Filter, finalized::FinalizedEvent,
+        intermediary::IntermediaryEvent, Event, HierarchicalId, Id, Msg,
+    },
+    subscription::{
+        EventSender, LaggingSubscription, OverflowPolicy, OwnedSubscription, PublisherRef,
+        Subscription, SubscriptionCore, SubscriptionError, SubscriptionSender,
+    },
     this_origin,
 };
 
@@ -55,19 +68,70 @@ pub trait CaptureControl {
     ///
     /// [req:cap.ctrl.stop]
     fn stop_id() -> Self;
+
+    /// Returns the ID of the control event that signals that capturing of the given [`Id`] was disabled,
+    /// or `None` if no control event should be sent.
+    /// The disabled ID is available via [`Event::get_signaled_id`] of the sent control event.
+    ///
+    /// **Note:** No control events are sent per default.
+    /// The returned ID is only sent if [`is_signal_id`](Self::is_signal_id) is also implemented, and returns `true` for it.
+    ///
+    /// [req:cap.ctrl]
+    fn disabled_signal_id(_id: &Self) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
+    /// Returns the ID of the control event that signals that capturing of the given [`Id`] was enabled again,
+    /// or `None` if no control event should be sent.
+    /// The enabled ID is available via [`Event::get_signaled_id`] of the sent control event.
+    ///
+    /// **Note:** No control events are sent per default.
+    /// The returned ID is only sent if [`is_signal_id`](Self::is_signal_id) is also implemented, and returns `true` for it.
+    ///
+    /// [req:cap.ctrl]
+    fn enabled_signal_id(_id: &Self) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
+    /// Returns `true` if the given [`Id`] is returned by [`disabled_signal_id`](Self::disabled_signal_id)
+    /// or [`enabled_signal_id`](Self::enabled_signal_id).
+    ///
+    /// **Note:** Must be implemented if one of the signal functions is implemented,
+    /// so signal events are not affected by the filter, or disabled IDs.
+    /// Signal IDs that are not recognized by this function are never sent.
+    ///
+    /// [req:cap.ctrl]
+    fn is_signal_id(_id: &Self) -> bool {
+        false
+    }
 }
 
 /// Returns `true` if the given [`Id`] is used to control capturing.
 ///
 /// [req:cap.ctrl]
 pub fn is_control_id(id: &impl CaptureControl) -> bool {
-    CaptureControl::stop(id) || CaptureControl::start(id)
+    CaptureControl::stop(id) || CaptureControl::start(id) || CaptureControl::is_signal_id(id)
 }
 
+/// Channel bound used to capture events, if no bound is set explicitly.
+pub const DEFAULT_CAPTURE_CHANNEL_BOUND: usize = 1000;
+
+/// Channel bound used per subscription, if no bound is set explicitly.
+pub const DEFAULT_SUBSCRIPTION_CHANNEL_BOUND: usize = 1000;
+
 /// Defines the capture mode for a publisher.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum CaptureMode {
     /// Event capturing may be blocking if the capture buffer is full.
+    ///
+    /// **Note:** This is the default capture mode.
+    #[default]
     Blocking,
     /// Event capturing does not block, resulting in events **not** being captured if the capture buffer is full.
     ///
@@ -75,13 +139,16 @@ pub enum CaptureMode {
     NonBlocking,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum EventTimestampKind {
     /// Sets the event time, when the event is captured.
     ///
+    /// **Note:** This is the default timestamp kind.
+    ///
     /// **Note:** With this setting, event timestamps might show incorrect order in case of concurrent events, because events are buffered before capturing.
     ///
     /// **Note:** This has slightly better performance on the thread setting an event, because system time access is delayed to the capturing thread.
+    #[default]
     Captured,
     /// Sets the event time, when the event is created.
     ///
@@ -89,11 +156,446 @@ pub enum EventTimestampKind {
     Created,
 }
 
+/// Possible errors when waiting for an event to be dispatched to its subscribers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DispatchError {
+    /// The event was not captured, because it was filtered, capturing is stopped, or the capture buffer was full.
+    NotCaptured,
+
+    /// The event was not dispatched within the given timeout.
+    Timeout,
+
+    /// The publisher was shut down before the event was dispatched.
+    PublisherShutDown,
+}
+
+/// Outcome of capturing an event.
+///
+/// [req:cap]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CaptureOutcome {
+    /// The event was sent to the event handler.
+    Captured,
+
+    /// The event was not captured, because the filter of the publisher did not allow it.
+    Filtered,
+
+    /// The event was not captured, because capturing was stopped.
+    CaptureStopped,
+
+    /// The event was not captured, because its event-ID was disabled.
+    IdDisabled,
+
+    /// The event was not captured in *non-blocking* mode, because the capture buffer was full.
+    DroppedFull,
+
+    /// The event was not captured, because the publisher was shut down.
+    PublisherGone,
+
+    /// The event was not captured again, because its entry was already taken by an earlier capture.
+    AlreadyCaptured,
+}
+
+impl CaptureOutcome {
+    /// Returns `true` if the event was sent to the event handler.
+    pub fn is_captured(&self) -> bool {
+        *self == CaptureOutcome::Captured
+    }
+}
+
+/// Snapshot of statistics about an [`EvidentPublisher`].
+///
+/// **Note:** Counters are updated concurrently, so values in one snapshot may not be consistent to each other.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublisherStats<K: Id> {
+    /// Number of events that were sent to the capturing channel.
+    pub captured: usize,
+
+    /// Number of events that were not captured, because the filter of the publisher did not allow them.
+    pub filtered: usize,
+
+    /// Number of events that were not captured, because capturing was stopped.
+    pub ignored_while_stopped: usize,
+
+    /// Number of events that were not captured, because their event-ID was disabled.
+    pub ignored_while_disabled: usize,
+
+    /// Number of captured events that were forwarded to the event handler.
+    pub dispatched: usize,
+
+    /// Number of captured events that were coalesced into a previous event with the same event-ID and message.
+    ///
+    /// **Note:** Coalesced events are not counted as dispatched.
+    pub coalesced: usize,
+
+    /// Number of events that were not captured in *non-blocking* mode, because the capture buffer was full.
+    ///
+    /// **Note:** Same as `get_missed_captures()`, so this value is affected by `reset_missed_captures()`.
+    pub dropped: usize,
+
+    /// Number of captured events per event-ID.
+    pub captures_per_id: HashMap<K, usize>,
+
+    /// Number of subscriptions per event-ID.
+    ///
+    /// **Note:** Subscriptions to multiple event-IDs are counted once per event-ID.
+    pub subscriptions_per_id: HashMap<K, usize>,
+
+    /// Number of prefix subscriptions per event-ID.
+    pub prefix_subscriptions_per_id: HashMap<K, usize>,
+
+    /// Number of subscriptions to all events.
+    pub all_events_subscriptions: usize,
+
+    /// Number of captured events that were not yet forwarded to the event handler.
+    pub capture_queue_depth: usize,
+
+    /// The bound of the capturing channel.
+    pub capture_channel_bound: usize,
+
+    /// The bound used per subscription channel.
+    pub subscription_channel_bound: usize,
+
+    /// Number of disconnected subscription channels that were removed from the publisher.
+    pub dead_channels_removed: usize,
+}
+
+/// Counters used to create [`PublisherStats`].
+struct StatsCounters<K: Id> {
+    captured: AtomicUsize,
+    filtered: AtomicUsize,
+    ignored_while_stopped: AtomicUsize,
+    ignored_while_disabled: AtomicUsize,
+    dispatched: AtomicUsize,
+    coalesced: AtomicUsize,
+    dead_channels_removed: AtomicUsize,
+    captures_per_id: Mutex<HashMap<K, usize>>,
+}
+
+impl<K: Id> Default for StatsCounters<K> {
+    fn default() -> Self {
+        StatsCounters {
+            captured: AtomicUsize::new(0),
+            filtered: AtomicUsize::new(0),
+            ignored_while_stopped: AtomicUsize::new(0),
+            ignored_while_disabled: AtomicUsize::new(0),
+            dispatched: AtomicUsize::new(0),
+            coalesced: AtomicUsize::new(0),
+            dead_channels_removed: AtomicUsize::new(0),
+            captures_per_id: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+/// Bounded history of the last events that were forwarded to subscribers,
+/// and the last event of every retained event-ID.
+///
+/// Used to replay retained events to late subscribers.
+struct ReplayBuffer<K, M, T>
+where
+    K: Id,
+    M: Msg,
+    T: EventEntry<K, M>,
+{
+    /// Maximum number of events retained over all event-IDs.
+    capacity: usize,
+
+    /// Maximum number of events retained per event-ID.
+    capacity_per_id: usize,
+
+    /// Sequence number of the next event, used to restore the order of retained events,
+    /// and to forward live events only to subscriptions that were added before the event was retained.
+    next_seq: u64,
+
+    /// The last events over all event-IDs.
+    events: VecDeque<RetainedEvent<K, M, T>>,
+
+    /// The last events per event-ID.
+    events_per_id: HashMap<K, VecDeque<RetainedEvent<K, M, T>>>,
+
+    /// The last event of every event-ID that is marked as retained, or `None` if no event was received yet.
+    last_events: HashMap<K, Option<RetainedEvent<K, M, T>>>,
+}
+
+type RetainedEvent<K, M, T> = (u64, Arc<Event<K, M, T>>);
+
+impl<K, M, T> ReplayBuffer<K, M, T>
+where
+    K: Id,
+    M: Msg,
+    T: EventEntry<K, M>,
+{
+    fn new() -> Self {
+        ReplayBuffer {
+            capacity: 0,
+            capacity_per_id: 0,
+            next_seq: 0,
+            events: VecDeque::new(),
+            events_per_id: HashMap::new(),
+            last_events: HashMap::new(),
+        }
+    }
+
+    /// Retains the given event, dropping the oldest retained events if capacities are exceeded.
+    ///
+    /// Returns the sequence number of the event.
+    fn retain(&mut self, event: &Arc<Event<K, M, T>>) -> u64 {
+        let seq = self.next_seq;
+        self.next_seq += 1;
+
+        if let Some(last_event) = self.last_events.get_mut(event.get_event_id()) {
+            *last_event = Some((seq, event.clone()));
+        }
+
+        if self.capacity > 0 {
+            if self.events.len() >= self.capacity {
+                self.events.pop_front();
+            }
+            self.events.push_back((seq, event.clone()));
+        }
+
+        if self.capacity_per_id > 0 {
+            let id_events = self
+                .events_per_id
+                .entry(event.get_event_id().clone())
+                .or_default();
+            if id_events.len() >= self.capacity_per_id {
+                id_events.pop_front();
+            }
+            id_events.push_back((seq, event.clone()));
+        }
+
+        seq
+    }
+
+    fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+
+        while self.events.len() > capacity {
+            self.events.pop_front();
+        }
+    }
+
+    fn set_capacity_per_id(&mut self, capacity_per_id: usize) {
+        self.capacity_per_id = capacity_per_id;
+
+        for id_events in self.events_per_id.values_mut() {
+            while id_events.len() > capacity_per_id {
+                id_events.pop_front();
+            }
+        }
+        self.events_per_id
+            .retain(|_, id_events| !id_events.is_empty());
+    }
+
+    /// Returns up to `n` of the latest retained events, and the last events of the given retained event-IDs,
+    /// in the order they were retained.
+    ///
+    /// Only events with one of the given event-IDs are returned, or all events if no event-IDs are given.
+    fn latest(&self, ids: Option<&[K]>, n: usize) -> Vec<Arc<Event<K, M, T>>> {
+        // Note: Sequence numbers are used to restore order, and remove events retained in multiple histories.
+        let mut retained: BTreeMap<u64, &Arc<Event<K, M, T>>> = BTreeMap::new();
+
+        match ids {
+            Some(ids) => {
+                for (seq, event) in self.events.iter() {
+                    if ids.contains(event.get_event_id()) {
+                        retained.insert(*seq, event);
+                    }
+                }
+                for id in ids {
+                    if let Some(id_events) = self.events_per_id.get(id) {
+                        retained.extend(id_events.iter().map(|(seq, event)| (*seq, event)));
+                    }
+                }
+            }
+            None => {
+                retained.extend(self.events.iter().map(|(seq, event)| (*seq, event)));
+                for id_events in self.events_per_id.values() {
+                    retained.extend(id_events.iter().map(|(seq, event)| (*seq, event)));
+                }
+            }
+        }
+
+        let skip = retained.len().saturating_sub(n);
+        let mut latest: BTreeMap<u64, &Arc<Event<K, M, T>>> =
+            retained.into_iter().skip(skip).collect();
+
+        // Note: Last events of retained event-IDs are always returned to subscriptions to specific events.
+        for id in ids.unwrap_or_default() {
+            if let Some(Some((seq, event))) = self.last_events.get(id) {
+                latest.insert(*seq, event);
+            }
+        }
+
+        latest.into_values().cloned().collect()
+    }
+}
+
 // Types below used for better clarity according to clippy.
 
 type Subscriber<K, M, T> = HashMap<crate::uuid::Uuid, SubscriptionSender<K, M, T>>;
-type IdSubscriber<K, M, T> = HashMap<K, Subscriber<K, M, T>>;
-type Capturer<K, M, T> = SyncSender<Event<K, M, T>>;
+pub(crate) type IdSubscriber<K, M, T> = HashMap<K, Subscriber<K, M, T>>;
+type Capturer<K, M, T> = SyncSender<CaptureMsg<K, M, T>>;
+type EventReceiver<K, M, T> = Receiver<Arc<Event<K, M, T>>>;
+type DispatchWaiter = HashMap<crate::uuid::Uuid, SyncSender<bool>>;
+type CoalesceWindows<K, M> = Arc<RwLock<HashMap<K, CoalesceWindow<M>>>>;
+
+/// Window in which repetitions of events with the same event-ID and message are coalesced.
+#[derive(Clone)]
+struct CoalesceWindow<M> {
+    duration: Duration,
+
+    /// Function to compare messages of repetitions.
+    ///
+    /// **Note:** Stored per window, because [`Msg`] does not require `PartialEq`.
+    msg_eq: fn(&M, &M) -> bool,
+}
+
+impl<M: Msg + PartialEq> CoalesceWindow<M> {
+    fn new(duration: Duration) -> Self {
+        CoalesceWindow {
+            duration,
+            msg_eq: M::eq,
+        }
+    }
+}
+
+impl<M> CoalesceWindow<M> {
+    /// Returns `true` if both messages are equal, or both events have no message.
+    fn same_msg(&self, first: Option<&M>, second: Option<&M>) -> bool {
+        match (first, second) {
+            (Some(first), Some(second)) => (self.msg_eq)(first, second),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Event that is held back by the capture thread to coalesce repetitions with the same event-ID and message.
+struct PendingEvent<K, M, T>
+where
+    K: Id,
+    M: Msg,
+    T: EventEntry<K, M>,
+{
+    /// The first repetition of the event.
+    event: Event<K, M, T>,
+
+    /// Point in time the event is dispatched at the latest.
+    ///
+    /// **Note:** The deadline is fixed at the first repetition, so following repetitions do not extend the window.
+    deadline: Instant,
+
+    /// Entry-IDs of the repetitions that were coalesced into the event.
+    coalesced_entry_ids: Vec<crate::uuid::Uuid>,
+}
+
+/// Events held back by the capture thread per event-ID to coalesce repetitions.
+///
+/// [req:cap]
+struct Coalescer<K, M, T>
+where
+    K: Id,
+    M: Msg,
+    T: EventEntry<K, M>,
+{
+    pending: HashMap<K, PendingEvent<K, M, T>>,
+}
+
+impl<K, M, T> Coalescer<K, M, T>
+where
+    K: Id,
+    M: Msg,
+    T: EventEntry<K, M>,
+{
+    fn new() -> Self {
+        Coalescer {
+            pending: HashMap::new(),
+        }
+    }
+
+    /// Returns the earliest deadline of all pending events, or `None` if no event is pending.
+    fn next_deadline(&self) -> Option<Instant> {
+        self.pending.values().map(|pending| pending.deadline).min()
+    }
+
+    /// Holds back the given event, or coalesces it into the pending event with the same event-ID and message.
+    ///
+    /// Returns `true` if the event was coalesced, and the previously pending event
+    /// if it must be dispatched, because its message differs from the given event.
+    fn hold(
+        &mut self,
+        event: Event<K, M, T>,
+        window: &CoalesceWindow<M>,
+    ) -> (bool, Option<PendingEvent<K, M, T>>) {
+        match self.pending.get_mut(event.get_event_id()) {
+            Some(pending) if window.same_msg(pending.event.get_msg(), event.get_msg()) => {
+                pending.event.repetitions += 1;
+                pending.event.last_timestamp = event.timestamp;
+                pending.coalesced_entry_ids.push(event.get_entry_id());
+                (true, None)
+            }
+            _ => {
+                let id = event.get_event_id().clone();
+                let pending = PendingEvent {
+                    event,
+                    deadline: Instant::now() + window.duration,
+                    coalesced_entry_ids: Vec::new(),
+                };
+                (false, self.pending.insert(id, pending))
+            }
+        }
+    }
+
+    /// Removes and returns all pending events whose deadline passed.
+    fn take_expired(&mut self, now: Instant) -> Vec<PendingEvent<K, M, T>> {
+        let expired_ids: Vec<K> = self
+            .pending
+            .iter()
+            .filter(|(_, pending)| pending.deadline <= now)
+            .map(|(id, _)| id.clone())
+            .collect();
+
+        expired_ids
+            .iter()
+            .filter_map(|id| self.pending.remove(id))
+            .collect()
+    }
+
+    /// Removes and returns all pending events.
+    fn take_all(&mut self) -> Vec<PendingEvent<K, M, T>> {
+        self.pending.drain().map(|(_, pending)| pending).collect()
+    }
+}
+
+/// Notifies a thread waiting for the event with the given entry-ID, if such a thread exists.
+fn notify_waiter(waiters: &Mutex<DispatchWaiter>, entry_id: crate::uuid::Uuid, dispatched: bool) {
+    if let Ok(mut locked_waiters) = waiters.lock() {
+        if let Some(waiter) = locked_waiters.remove(&entry_id) {
+            let _ = waiter.try_send(dispatched);
+        }
+    }
+}
+
+/// Message that is sent over the capturing channel to the event handler thread.
+///
+/// [req:cap]
+pub(crate) enum CaptureMsg<K, M, T>
+where
+    K: Id,
+    M: Msg,
+    T: EventEntry<K, M>,
+{
+    /// A captured event that is forwarded to the event handler.
+    Event(Event<K, M, T>),
+
+    /// Notifies the given sender once all messages sent before this one were handled.
+    Flush(SyncSender<()>),
+
+    /// Stops the event handler thread once all messages sent before this one were handled.
+    Shutdown,
+}
 
 /// An **EvidentPublisher** is used to capture, publish, and manage subscriptions.
 ///
@@ -110,6 +612,16 @@ where
     /// [req:subs.specific]
     pub(crate) subscriptions: Arc<RwLock<IdSubscriber<K, M, T>>>,
 
+    /// The hashmap of subscribers listening to events with specific event-IDs, or any of their descendants.
+    ///
+    /// **Note:** Only used for [`HierarchicalId`]s.
+    pub(crate) prefix_subscriptions: Arc<RwLock<IdSubscriber<K, M, T>>>,
+
+    /// Function to get the parent of an event-ID.
+    ///
+    /// **Note:** Only set once the first prefix subscription is created, since it requires [`HierarchicalId`].
+    parent_of: OnceCell<fn(&K) -> Option<K>>,
+
     /// The hashmap of subscribers listening to all events.
     ///
     /// [req:subs.all]
@@ -122,14 +634,21 @@ where
 
     /// Optional filter that is applied when capturing events.
     ///
+    /// **Note:** The filter is shared using an [`Arc`], so it may be replaced while events are captured concurrently.
+    ///
     /// [req:cap.filter]
-    filter: Option<F>,
+    filter: RwLock<Option<Arc<F>>>,
 
     /// Flag to control if capturing is active or inactive.
     ///
     /// [req:cap.ctrl]
     capturing: Arc<AtomicBool>,
 
+    /// Event-IDs that are not captured, regardless of the filter.
+    ///
+    /// [req:cap.ctrl]
+    disabled_ids: RwLock<HashSet<K>>,
+
     /// Flag to control the capture mode.
     capture_blocking: Arc<AtomicBool>,
 
@@ -148,59 +667,361 @@ where
 
     /// Defines at what point the event-timestamp is created.
     timestamp_kind: EventTimestampKind,
+
+    /// Handle of the thread that forwards captured events to the event handler.
+    ///
+    /// **Note:** Only `None` after the publisher was shut down.
+    capture_thread: Mutex<Option<JoinHandle<()>>>,
+
+    /// Flag set to `true` once the publisher was shut down.
+    shut_down: Arc<AtomicBool>,
+
+    /// Counters used to create [`PublisherStats`].
+    stats: Arc<StatsCounters<K>>,
+
+    /// Channels to notify threads waiting for events with the given entry-IDs to be dispatched.
+    ///
+    /// **Note:** `true` is sent once the event was dispatched, and `false` if the event was not captured.
+    dispatch_waiters: Arc<Mutex<DispatchWaiter>>,
+
+    /// Windows per event-ID in which repetitions of an event with the same message are coalesced.
+    coalesce_windows: CoalesceWindows<K, M>,
+
+    /// History of the last events that were forwarded to subscribers, and the last events of retained event-IDs.
+    ///
+    /// **Note:** The lock is only held to retain an event or to take a snapshot for a new subscription.
+    /// Sequence numbers ensure that replayed and live events have neither gaps nor duplicates.
+    replay_buffer: Mutex<ReplayBuffer<K, M, T>>,
 }
 
-impl<K, M, T, F> EvidentPublisher<K, M, T, F>
+/// Builder to create an [`EvidentPublisher`].
+///
+/// **Default settings:**
+///
+/// - No filter
+/// - [`CaptureMode::Blocking`]
+/// - [`DEFAULT_CAPTURE_CHANNEL_BOUND`] and [`DEFAULT_SUBSCRIPTION_CHANNEL_BOUND`]
+/// - [`EventTimestampKind::Captured`]
+/// - Unnamed capture thread with the default stack size of [`std::thread`]
+///
+/// **Note:** Use [`build`](Self::build) for static publishers, and [`build_shared`](Self::build_shared) for publishers created at runtime.
+///
+/// [req:pub]
+pub struct EvidentPublisherBuilder<K, M, T, F>
 where
     K: Id + CaptureControl,
     M: Msg,
     T: EventEntry<K, M>,
     F: Filter<K, M>,
 {
-    /// Create a new [`EvidentPublisher`], and spawn a new event handler thread for events captured by the publisher.
-    ///
-    /// **Note:** You should use the macro [`create_static_publisher`](crate::create_static_publisher) instead.
-    ///
-    /// [req:pub]
-    fn create(
-        mut on_event: impl FnMut(Event<K, M, T>) + std::marker::Send + 'static,
-        filter: Option<F>,
-        capture_mode: CaptureMode,
-        capture_channel_bound: usize,
-        subscription_channel_bound: usize,
-        timestamp_kind: EventTimestampKind,
-    ) -> Self {
-        let (send, recv): (SyncSender<Event<K, M, T>>, _) =
-            mpsc::sync_channel(capture_channel_bound);
+    filter: Option<F>,
+    capture_mode: CaptureMode,
+    capture_channel_bound: usize,
+    subscription_channel_bound: usize,
+    timestamp_kind: EventTimestampKind,
+    thread_name: Option<String>,
+    stack_size: Option<usize>,
+    coalesce_windows: HashMap<K, CoalesceWindow<M>>,
 
-        // [req:pub.threaded]
-        thread::spawn(move || {
-            while let Ok(mut event) = recv.recv() {
-                if timestamp_kind == EventTimestampKind::Captured {
-                    event.timestamp = Some(std::time::SystemTime::now());
-                }
+    // PahmtomData needed for unused generics
+    phantom_k: PhantomData<K>,
+    phantom_m: PhantomData<M>,
+    phantom_t: PhantomData<T>,
+}
 
-                on_event(event);
-            }
-        });
+impl<K, M, T, F> Default for EvidentPublisherBuilder<K, M, T, F>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+    T: EventEntry<K, M>,
+    F: Filter<K, M>,
+{
+    fn default() -> Self {
+        EvidentPublisherBuilder {
+            filter: None,
+            capture_mode: CaptureMode::default(),
+            capture_channel_bound: DEFAULT_CAPTURE_CHANNEL_BOUND,
+            subscription_channel_bound: DEFAULT_SUBSCRIPTION_CHANNEL_BOUND,
+            timestamp_kind: EventTimestampKind::default(),
+            thread_name: None,
+            stack_size: None,
+            coalesce_windows: HashMap::new(),
+            phantom_k: PhantomData,
+            phantom_m: PhantomData,
+            phantom_t: PhantomData,
+        }
+    }
+}
 
-        let mode = match capture_mode {
-            CaptureMode::Blocking => Arc::new(AtomicBool::new(true)),
-            CaptureMode::NonBlocking => Arc::new(AtomicBool::new(false)),
-        };
+impl<K, M, T, F> EvidentPublisherBuilder<K, M, T, F>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+    T: EventEntry<K, M>,
+    F: Filter<K, M>,
+{
+    /// Creates a new builder with default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
 
-        EvidentPublisher {
-            subscriptions: Arc::new(RwLock::new(HashMap::new())),
+    /// Sets the filter used to decide which events are captured.
+    ///
+    /// [req:cap.filter]
+    pub fn filter(mut self, filter: F) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Sets the [`CaptureMode`] of the publisher.
+    pub fn capture_mode(mut self, capture_mode: CaptureMode) -> Self {
+        self.capture_mode = capture_mode;
+        self
+    }
+
+    /// Sets the channel bound used to capture events.
+    pub fn capture_channel_bound(mut self, bound: usize) -> Self {
+        self.capture_channel_bound = bound;
+        self
+    }
+
+    /// Sets the channel bound used per subscription.
+    pub fn subscription_channel_bound(mut self, bound: usize) -> Self {
+        self.subscription_channel_bound = bound;
+        self
+    }
+
+    /// Sets the [`EventTimestampKind`] of the publisher.
+    pub fn timestamp_kind(mut self, timestamp_kind: EventTimestampKind) -> Self {
+        self.timestamp_kind = timestamp_kind;
+        self
+    }
+
+    /// Sets the name of the capture thread that forwards captured events to subscribers.
+    pub fn thread_name(mut self, name: impl Into<String>) -> Self {
+        self.thread_name = Some(name.into());
+        self
+    }
+
+    /// Sets the stack size in bytes of the capture thread that forwards captured events to subscribers.
+    pub fn stack_size(mut self, size: usize) -> Self {
+        self.stack_size = Some(size);
+        self
+    }
+
+    /// Sets the window in which repetitions of events with the given event-ID and the same message are coalesced.
+    ///
+    /// See [`EvidentPublisher::set_coalesce_window`] for more information.
+    pub fn coalesce_window(mut self, id: K, window: Duration) -> Self
+    where
+        M: PartialEq,
+    {
+        self.coalesce_windows
+            .insert(id, CoalesceWindow::new(window));
+        self
+    }
+
+    /// Creates the [`EvidentPublisher`], and spawns the capture thread that passes captured events to the given handler.
+    ///
+    /// **Note:** The handler of a static publisher must call `on_event()` of the publisher to forward events to subscribers.
+    /// See [`create_static_publisher`](crate::create_static_publisher) for a convenience wrapper.
+    ///
+    /// # Panics
+    ///
+    /// If the capture thread could not be spawned.
+    ///
+    /// [req:pub]
+    pub fn build(
+        self,
+        on_event: impl FnMut(Event<K, M, T>) + std::marker::Send + 'static,
+    ) -> EvidentPublisher<K, M, T, F> {
+        EvidentPublisher::create(on_event, self)
+    }
+
+    /// Creates the [`EvidentPublisher`] that forwards captured events to its own subscribers, and is shared using an [`Arc`].
+    ///
+    /// # Panics
+    ///
+    /// If the capture thread could not be spawned.
+    ///
+    /// [req:pub]
+    pub fn build_shared(self) -> Arc<EvidentPublisher<K, M, T, F>>
+    where
+        F: Send + Sync + 'static,
+    {
+        EvidentPublisher::create_shared(self)
+    }
+}
+
+impl<K, M, T, F> EvidentPublisher<K, M, T, F>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+    T: EventEntry<K, M>,
+    F: Filter<K, M>,
+{
+    /// Returns a builder to create an [`EvidentPublisher`] with default settings.
+    ///
+    /// See [`EvidentPublisherBuilder`] for the default settings.
+    ///
+    /// [req:pub]
+    pub fn builder() -> EvidentPublisherBuilder<K, M, T, F> {
+        EvidentPublisherBuilder::new()
+    }
+
+    /// Create a new [`EvidentPublisher`] with the settings of the given builder,
+    /// and spawn a new event handler thread for events captured by the publisher.
+    ///
+    /// [req:pub]
+    fn create(
+        mut on_event: impl FnMut(Event<K, M, T>) + std::marker::Send + 'static,
+        settings: EvidentPublisherBuilder<K, M, T, F>,
+    ) -> Self {
+        let EvidentPublisherBuilder {
+            filter,
+            capture_mode,
+            capture_channel_bound,
+            subscription_channel_bound,
+            timestamp_kind,
+            thread_name,
+            stack_size,
+            coalesce_windows,
+            ..
+        } = settings;
+
+        let (send, recv): (Capturer<K, M, T>, _) = mpsc::sync_channel(capture_channel_bound);
+        let stats: Arc<StatsCounters<K>> = Arc::new(StatsCounters::default());
+        let thread_stats = stats.clone();
+        let dispatch_waiters: Arc<Mutex<DispatchWaiter>> = Arc::new(Mutex::new(HashMap::new()));
+        let thread_waiters = dispatch_waiters.clone();
+        let coalesce_windows: CoalesceWindows<K, M> = Arc::new(RwLock::new(coalesce_windows));
+        let thread_windows = coalesce_windows.clone();
+
+        let mut thread_builder = thread::Builder::new();
+        if let Some(name) = thread_name {
+            thread_builder = thread_builder.name(name);
+        }
+        if let Some(size) = stack_size {
+            thread_builder = thread_builder.stack_size(size);
+        }
+
+        // [req:pub.threaded]
+        let capture_thread = thread_builder
+            .spawn(move || {
+                let mut coalescer: Coalescer<K, M, T> = Coalescer::new();
+                let mut dispatch = |event: Event<K, M, T>, coalesced_entry_ids: Vec<_>| {
+                    on_event(event);
+
+                    thread_stats.dispatched.fetch_add(1, Ordering::Relaxed);
+
+                    // Note: Coalesced repetitions are dispatched as part of the first repetition.
+                    for entry_id in coalesced_entry_ids {
+                        notify_waiter(&thread_waiters, entry_id, true);
+                    }
+                };
+
+                loop {
+                    // Note: Pending events must be dispatched once their coalesce window passed.
+                    let msg = match coalescer.next_deadline() {
+                        Some(deadline) => {
+                            match recv
+                                .recv_timeout(deadline.saturating_duration_since(Instant::now()))
+                            {
+                                Ok(msg) => Some(msg),
+                                Err(RecvTimeoutError::Timeout) => None,
+                                Err(RecvTimeoutError::Disconnected) => break,
+                            }
+                        }
+                        None => match recv.recv() {
+                            Ok(msg) => Some(msg),
+                            Err(_) => break,
+                        },
+                    };
+
+                    match msg {
+                        Some(CaptureMsg::Event(mut event)) => {
+                            // Note: Injected events may already have a timestamp.
+                            if timestamp_kind == EventTimestampKind::Captured
+                                && event.timestamp.is_none()
+                            {
+                                event.timestamp = Some(std::time::SystemTime::now());
+                            }
+
+                            if let Ok(mut captures_per_id) = thread_stats.captures_per_id.lock() {
+                                *captures_per_id
+                                    .entry(event.get_event_id().clone())
+                                    .or_default() += 1;
+                            }
+
+                            let window = if is_control_id(event.get_event_id()) {
+                                None
+                            } else {
+                                thread_windows.read().ok().and_then(|locked_windows| {
+                                    locked_windows.get(event.get_event_id()).cloned()
+                                })
+                            };
+
+                            match window {
+                                Some(window) => {
+                                    let (coalesced, replaced) = coalescer.hold(event, &window);
+                                    if coalesced {
+                                        thread_stats.coalesced.fetch_add(1, Ordering::Relaxed);
+                                    }
+                                    if let Some(pending) = replaced {
+                                        dispatch(pending.event, pending.coalesced_entry_ids);
+                                    }
+                                }
+                                None => dispatch(event, Vec::new()),
+                            }
+                        }
+                        Some(CaptureMsg::Flush(done)) => {
+                            for pending in coalescer.take_all() {
+                                dispatch(pending.event, pending.coalesced_entry_ids);
+                            }
+                            let _ = done.send(());
+                        }
+                        Some(CaptureMsg::Shutdown) => {
+                            break;
+                        }
+                        None => {}
+                    }
+
+                    for pending in coalescer.take_expired(Instant::now()) {
+                        dispatch(pending.event, pending.coalesced_entry_ids);
+                    }
+                }
+
+                for pending in coalescer.take_all() {
+                    dispatch(pending.event, pending.coalesced_entry_ids);
+                }
+            })
+            .expect("Failed to spawn the capture thread of the publisher.");
+
+        let mode = match capture_mode {
+            CaptureMode::Blocking => Arc::new(AtomicBool::new(true)),
+            CaptureMode::NonBlocking => Arc::new(AtomicBool::new(false)),
+        };
+        EvidentPublisher {
+            subscriptions: Arc::new(RwLock::new(HashMap::new())),
+            prefix_subscriptions: Arc::new(RwLock::new(HashMap::new())),
+            parent_of: OnceCell::new(),
             any_event: Arc::new(RwLock::new(HashMap::new())),
             capturer: send,
-            filter,
+            filter: RwLock::new(filter.map(Arc::new)),
             // [req:cap.ctrl.init]
             capturing: Arc::new(AtomicBool::new(true)),
+            disabled_ids: RwLock::new(HashSet::new()),
             capture_blocking: mode,
             capture_channel_bound,
             subscription_channel_bound,
             missed_captures: Arc::new(AtomicUsize::new(0)),
             timestamp_kind,
+            capture_thread: Mutex::new(Some(capture_thread)),
+            shut_down: Arc::new(AtomicBool::new(false)),
+            stats,
+            dispatch_waiters,
+            coalesce_windows,
+            replay_buffer: Mutex::new(ReplayBuffer::new()),
         }
     }
 
@@ -216,14 +1037,12 @@ where
         subscription_channel_bound: usize,
         time_stamp_kind: EventTimestampKind,
     ) -> Self {
-        Self::create(
-            on_event,
-            None,
-            capture_mode,
-            capture_channel_bound,
-            subscription_channel_bound,
-            time_stamp_kind,
-        )
+        Self::builder()
+            .capture_mode(capture_mode)
+            .capture_channel_bound(capture_channel_bound)
+            .subscription_channel_bound(subscription_channel_bound)
+            .timestamp_kind(time_stamp_kind)
+            .build(on_event)
     }
 
     /// Create a new [`EvidentPublisher`] with an event filter.
@@ -239,25 +1058,130 @@ where
         subscription_channel_bound: usize,
         timestamp_kind: EventTimestampKind,
     ) -> Self {
-        Self::create(
-            on_event,
-            Some(filter),
-            capture_mode,
-            capture_channel_bound,
-            subscription_channel_bound,
-            timestamp_kind,
-        )
+        Self::builder()
+            .filter(filter)
+            .capture_mode(capture_mode)
+            .capture_channel_bound(capture_channel_bound)
+            .subscription_channel_bound(subscription_channel_bound)
+            .timestamp_kind(timestamp_kind)
+            .build(on_event)
+    }
+
+    /// Create a new [`EvidentPublisher`] without an event filter that is shared using an [`Arc`].
+    ///
+    /// Captured events are forwarded to subscribers of the returned publisher,
+    /// and [`OwnedSubscription`]s may be created using the `subscribe_*_owned` functions.
+    ///
+    /// **Note:** Use this function to create publishers at runtime, instead of a static publisher created with [`create_static_publisher`](crate::create_static_publisher).
+    ///
+    /// [req:pub]
+    pub fn new_shared(
+        capture_mode: CaptureMode,
+        capture_channel_bound: usize,
+        subscription_channel_bound: usize,
+        timestamp_kind: EventTimestampKind,
+    ) -> Arc<Self>
+    where
+        F: Send + Sync + 'static,
+    {
+        Self::builder()
+            .capture_mode(capture_mode)
+            .capture_channel_bound(capture_channel_bound)
+            .subscription_channel_bound(subscription_channel_bound)
+            .timestamp_kind(timestamp_kind)
+            .build_shared()
+    }
+
+    /// Create a new [`EvidentPublisher`] with an event filter that is shared using an [`Arc`].
+    ///
+    /// See [`new_shared`](Self::new_shared) for more information.
+    ///
+    /// [req:pub], [req:cap.filter]
+    pub fn with_shared(
+        filter: F,
+        capture_mode: CaptureMode,
+        capture_channel_bound: usize,
+        subscription_channel_bound: usize,
+        timestamp_kind: EventTimestampKind,
+    ) -> Arc<Self>
+    where
+        F: Send + Sync + 'static,
+    {
+        Self::builder()
+            .filter(filter)
+            .capture_mode(capture_mode)
+            .capture_channel_bound(capture_channel_bound)
+            .subscription_channel_bound(subscription_channel_bound)
+            .timestamp_kind(timestamp_kind)
+            .build_shared()
+    }
+
+    /// Create a new [`EvidentPublisher`] that forwards captured events to its own subscribers, and is shared using an [`Arc`].
+    ///
+    /// [req:pub]
+    fn create_shared(settings: EvidentPublisherBuilder<K, M, T, F>) -> Arc<Self>
+    where
+        F: Send + Sync + 'static,
+    {
+        Arc::new_cyclic(|weak_publisher: &Weak<Self>| {
+            // Note: A weak reference is used to not keep the publisher alive in its own event handler thread.
+            let weak_publisher = weak_publisher.clone();
+
+            Self::create(
+                move |event| {
+                    if let Some(publisher) = weak_publisher.upgrade() {
+                        publisher.on_event(event);
+                    }
+                },
+                settings,
+            )
+        })
+    }
+
+    /// Returns the current event filter shared using an [`Arc`], or `None` if no filter is set.
+    ///
+    /// **Note:** The returned filter is not affected by later calls to [`set_filter`](Self::set_filter) or [`clear_filter`](Self::clear_filter).
+    ///
+    /// [req:cap.filter]
+    pub fn get_filter(&self) -> Option<Arc<F>> {
+        match self.filter.read() {
+            Ok(locked_filter) => locked_filter.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        }
+    }
+
+    /// Replaces the event filter, and returns the previous filter, or `None` if no filter was set.
+    ///
+    /// The new filter is applied to all events captured after this function returns.
+    /// Events that are captured concurrently are checked by either the previous or the new filter.
+    ///
+    /// [req:cap.filter]
+    pub fn set_filter(&self, filter: F) -> Option<Arc<F>> {
+        self.swap_filter(Some(Arc::new(filter)))
     }
 
-    /// Returns the event filter, or `None` if no filter is set.
+    /// Removes the event filter, so all events are captured, and returns the previous filter, or `None` if no filter was set.
+    ///
+    /// See [`set_filter`](Self::set_filter) for more information.
     ///
     /// [req:cap.filter]
-    pub fn get_filter(&self) -> &Option<F> {
-        &self.filter
+    pub fn clear_filter(&self) -> Option<Arc<F>> {
+        self.swap_filter(None)
+    }
+
+    fn swap_filter(&self, filter: Option<Arc<F>>) -> Option<Arc<F>> {
+        let mut locked_filter = match self.filter.write() {
+            Ok(locked_filter) => locked_filter,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        std::mem::replace(&mut *locked_filter, filter)
     }
 
     /// Returns `true` if the given event-entry passes the filter, or the event-ID is a control-ID.
     ///
+    /// **Note:** Entries of disabled event-IDs are not allowed, regardless of the filter.
+    ///
     /// [req:cap.filter]
     pub fn entry_allowed(&self, entry: &impl EventEntry<K, M>) -> bool {
         if !is_control_id(entry.get_event_id()) {
@@ -265,7 +1189,12 @@ where
                 return false;
             }
 
-            if let Some(filter) = &self.filter {
+            if !self.is_id_enabled(entry.get_event_id()) {
+                return false;
+            }
+
+            // Note: The filter is cloned to not hold the lock while it is applied.
+            if let Some(filter) = self.get_filter() {
                 if !filter.allow_entry(entry) {
                     return false;
                 }
@@ -282,11 +1211,58 @@ where
     /// [req:cap]
     #[doc(hidden)]
     pub fn _capture<I: IntermediaryEvent<K, M, T>>(&self, interm_event: &mut I) {
-        let entry = interm_event.take_entry();
+        // Note: No entry is left if the event was already captured (e.g. by `try_capture()`).
+        if let Some(entry) = interm_event.take_entry() {
+            self.capture_entry(entry);
+        }
+    }
+
+    /// Captures the entry of the given intermediary event, and returns the [`CaptureOutcome`].
+    ///
+    /// The entry is taken from the intermediary event, so it is not captured again on `drop()`.
+    /// [`CaptureOutcome::AlreadyCaptured`] is returned if the entry was already taken.
+    ///
+    /// [req:cap]
+    pub fn try_capture<I: IntermediaryEvent<K, M, T>>(
+        &self,
+        interm_event: &mut I,
+    ) -> CaptureOutcome {
+        match interm_event.take_entry() {
+            Some(entry) => self.capture_entry(entry),
+            None => CaptureOutcome::AlreadyCaptured,
+        }
+    }
+
+    /// Sends an event with the given entry to the event handler, if the entry is allowed.
+    fn capture_entry(&self, entry: T) -> CaptureOutcome {
+        let entry_id = entry.get_entry_id();
 
         // [req:cap.filter]
         if !self.entry_allowed(&entry) {
-            return;
+            // Note: Control-IDs are always allowed, so the filter is the cause if capturing is active, and the ID is enabled.
+            let outcome = if !self.is_capturing() {
+                self.stats
+                    .ignored_while_stopped
+                    .fetch_add(1, Ordering::Relaxed);
+
+                // Note: Capturing is stopped on shutdown.
+                if self.is_shut_down() {
+                    CaptureOutcome::PublisherGone
+                } else {
+                    CaptureOutcome::CaptureStopped
+                }
+            } else if !self.is_id_enabled(entry.get_event_id()) {
+                self.stats
+                    .ignored_while_disabled
+                    .fetch_add(1, Ordering::Relaxed);
+                CaptureOutcome::IdDisabled
+            } else {
+                self.stats.filtered.fetch_add(1, Ordering::Relaxed);
+                CaptureOutcome::Filtered
+            };
+
+            self.notify_dispatch_waiter(entry_id, false);
+            return outcome;
         }
 
         let mut event = Event::new(entry);
@@ -294,10 +1270,13 @@ where
             event.timestamp = Some(std::time::SystemTime::now());
         }
 
-        if self.capture_blocking.load(Ordering::Acquire) {
-            let _ = self.capturer.send(event);
+        let outcome = if self.capture_blocking.load(Ordering::Acquire) {
+            match self.capturer.send(CaptureMsg::Event(event)) {
+                Ok(_) => CaptureOutcome::Captured,
+                Err(_) => CaptureOutcome::PublisherGone,
+            }
         } else {
-            let res = self.capturer.try_send(event);
+            let res = self.capturer.try_send(CaptureMsg::Event(event));
 
             if let Err(TrySendError::Full(_)) = res {
                 // Note: If another thread has missed captures at the same moment, the count may be inaccurate, because there is no lock.
@@ -310,9 +1289,163 @@ where
                         .store(missed_captures + 1, Ordering::Relaxed);
                 }
             }
+
+            match res {
+                Ok(_) => CaptureOutcome::Captured,
+                Err(TrySendError::Full(_)) => CaptureOutcome::DroppedFull,
+                Err(TrySendError::Disconnected(_)) => CaptureOutcome::PublisherGone,
+            }
+        };
+
+        if outcome.is_captured() {
+            self.stats.captured.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.notify_dispatch_waiter(entry_id, false);
+        }
+        outcome
+    }
+
+    /// Finalizes the given intermediary event, and returns the [`CaptureOutcome`] of capturing the resulting event.
+    ///
+    /// May be used to fall back to another output if an event was not captured.
+    ///
+    /// **Note:** The event is captured by this publisher, even if the intermediary event would be captured by another publisher on `drop()`.
+    ///
+    /// [req:cap]
+    pub fn finalize_checked<I: IntermediaryEvent<K, M, T>>(
+        &self,
+        mut interm_event: I,
+    ) -> CaptureOutcome {
+        self.try_capture(&mut interm_event)
+    }
+
+    /// Sends the given event to the event handler, as if it was captured by this publisher.
+    ///
+    /// Returns `false` if the event could not be sent, because the publisher was shut down.
+    ///
+    /// May be used to re-inject events, e.g. events read from a journal.
+    /// The timestamp of the event is kept if it is already set.
+    ///
+    /// **Note:** Injected events bypass the filter, and are also sent if capturing is stopped.
+    /// This function blocks if the capture buffer is full, regardless of the capture mode.
+    ///
+    /// [req:cap]
+    pub fn inject_event(&self, event: Event<K, M, T>) -> bool {
+        if self.is_shut_down() {
+            return false;
+        }
+
+        let injected = self.capturer.send(CaptureMsg::Event(event)).is_ok();
+        if injected {
+            self.stats.captured.fetch_add(1, Ordering::Relaxed);
+        }
+        injected
+    }
+
+    /// Finalizes the given intermediary event, and blocks until the resulting event was dispatched to all its subscriptions.
+    ///
+    /// **Note:** The intermediary event must be captured by this publisher on `drop()`.
+    /// This is the case for all intermediary events used in [`create_static_publisher`](crate::create_static_publisher) of this publisher.
+    ///
+    /// **Note:** This function must **not** be called inside the event handler, because the handler would wait on itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `interm_event` ... The intermediary event to finalize
+    /// * `timeout` ... Optional maximum duration to wait for the event to be dispatched
+    ///
+    /// # Possible Errors
+    ///
+    /// * [`DispatchError::NotCaptured`] ... If the event was filtered, capturing is stopped, or the capture buffer was full
+    /// * [`DispatchError::Timeout`] ... If the event was not dispatched within the given timeout
+    /// * [`DispatchError::PublisherShutDown`] ... If the publisher was shut down before the event was dispatched
+    ///
+    /// [req:cap]
+    pub fn finalize_and_wait<I: IntermediaryEvent<K, M, T>>(
+        &self,
+        interm_event: I,
+        timeout: Option<Duration>,
+    ) -> Result<FinalizedEvent<K>, DispatchError> {
+        let entry_id = interm_event.get_entry().get_entry_id();
+        let (done_send, done_recv) = mpsc::sync_channel(1);
+
+        // Note: Waiter must be registered before capturing to not miss the dispatch.
+        match self.dispatch_waiters.lock() {
+            Ok(mut locked_waiters) => {
+                locked_waiters.insert(entry_id, done_send);
+            }
+            Err(_) => {
+                return Err(DispatchError::PublisherShutDown);
+            }
+        }
+
+        let finalized_event = interm_event.finalize();
+
+        let res = match timeout {
+            Some(duration) => done_recv.recv_timeout(duration),
+            None => done_recv.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+
+        match res {
+            Ok(true) => Ok(finalized_event),
+            Ok(false) => Err(DispatchError::NotCaptured),
+            Err(RecvTimeoutError::Timeout) => {
+                if let Ok(mut locked_waiters) = self.dispatch_waiters.lock() {
+                    locked_waiters.remove(&entry_id);
+                }
+                Err(DispatchError::Timeout)
+            }
+            Err(RecvTimeoutError::Disconnected) => Err(DispatchError::PublisherShutDown),
         }
     }
 
+    /// Notifies a thread waiting for the event with the given entry-ID, if such a thread exists.
+    fn notify_dispatch_waiter(&self, entry_id: crate::uuid::Uuid, dispatched: bool) {
+        notify_waiter(&self.dispatch_waiters, entry_id, dispatched);
+    }
+
+    /// Sets the window in which repetitions of events with the given event-ID and the same message are coalesced.
+    ///
+    /// The first repetition is held back by the capture thread until the window passed,
+    /// and all following repetitions with the same message are merged into it.
+    /// The window is fixed at the first repetition, so following repetitions do not extend it.
+    /// The dispatched event carries the number of repetitions, and the timestamps of the first and last repetition.
+    /// A repetition with a different message dispatches the held back event immediately, and starts a new window.
+    ///
+    /// Returns the previous window of the event-ID, or `None` if events of the event-ID were not coalesced.
+    ///
+    /// **Note:** Coalescing delays events of the event-ID, so they may be dispatched after events with other event-IDs that were set later.
+    /// Held back events are dispatched on `flush()` and shutdown. Control events are never coalesced.
+    /// Messages are compared using `PartialEq`.
+    ///
+    /// [req:cap]
+    pub fn set_coalesce_window(&self, id: K, window: Duration) -> Option<Duration>
+    where
+        M: PartialEq,
+    {
+        let window = CoalesceWindow::new(window);
+        let previous = match self.coalesce_windows.write() {
+            Ok(mut locked_windows) => locked_windows.insert(id, window),
+            Err(poisoned) => poisoned.into_inner().insert(id, window),
+        };
+        previous.map(|window| window.duration)
+    }
+
+    /// Stops coalescing repetitions of events with the given event-ID.
+    ///
+    /// Returns the removed window, or `None` if events of the event-ID were not coalesced.
+    ///
+    /// **Note:** An already held back event is still dispatched once its window passed.
+    ///
+    /// [req:cap]
+    pub fn remove_coalesce_window(&self, id: &K) -> Option<Duration> {
+        let removed = match self.coalesce_windows.write() {
+            Ok(mut locked_windows) => locked_windows.remove(id),
+            Err(poisoned) => poisoned.into_inner().remove(id),
+        };
+        removed.map(|window| window.duration)
+    }
+
     /// Returns the current capture mode.
     pub fn get_capture_mode(&self) -> CaptureMode {
         if self.capture_blocking.load(Ordering::Acquire) {
@@ -340,11 +1473,59 @@ where
         self.missed_captures.store(0, Ordering::Relaxed);
     }
 
+    /// Returns a snapshot of statistics about this publisher.
+    pub fn stats(&self) -> PublisherStats<K> {
+        let count_subscriptions = |id_subscriptions: &RwLock<IdSubscriber<K, M, T>>| {
+            id_subscriptions
+                .read()
+                .map(|locked_subs| {
+                    locked_subs
+                        .iter()
+                        .filter(|(_, sub_senders)| !sub_senders.is_empty())
+                        .map(|(id, sub_senders)| (id.clone(), sub_senders.len()))
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+
+        let captured = self.stats.captured.load(Ordering::Relaxed);
+        let dispatched = self.stats.dispatched.load(Ordering::Relaxed);
+        let coalesced = self.stats.coalesced.load(Ordering::Relaxed);
+
+        PublisherStats {
+            captured,
+            filtered: self.stats.filtered.load(Ordering::Relaxed),
+            ignored_while_stopped: self.stats.ignored_while_stopped.load(Ordering::Relaxed),
+            ignored_while_disabled: self.stats.ignored_while_disabled.load(Ordering::Relaxed),
+            dispatched,
+            coalesced,
+            dropped: self.get_missed_captures(),
+            captures_per_id: self
+                .stats
+                .captures_per_id
+                .lock()
+                .map(|captures_per_id| captures_per_id.clone())
+                .unwrap_or_default(),
+            subscriptions_per_id: count_subscriptions(&self.subscriptions),
+            prefix_subscriptions_per_id: count_subscriptions(&self.prefix_subscriptions),
+            all_events_subscriptions: self
+                .any_event
+                .read()
+                .map(|locked_vec| locked_vec.len())
+                .unwrap_or_default(),
+            // Note: Event might be dispatched before it is counted as captured.
+            capture_queue_depth: captured.saturating_sub(dispatched + coalesced),
+            capture_channel_bound: self.capture_channel_bound,
+            subscription_channel_bound: self.subscription_channel_bound,
+            dead_channels_removed: self.stats.dead_channels_removed.load(Ordering::Relaxed),
+        }
+    }
+
     /// Returns a subscription to events with the given event-ID,
     /// or a [`SubscriptionError<K>`] if the subscription could not be created.
     ///
     /// [req:subs.specific.one]
-    pub fn subscribe(&self, id: K) -> Result<Subscription<K, M, T, F>, SubscriptionError<K>> {
+    pub fn subscribe(&self, id: K) -> Result<Subscription<'_, K, M, T, F>, SubscriptionError<K>> {
         self.subscribe_to_many(vec![id])
     }
 
@@ -355,40 +1536,18 @@ where
     pub fn subscribe_to_many(
         &self,
         ids: Vec<K>,
-    ) -> Result<Subscription<K, M, T, F>, SubscriptionError<K>> {
+    ) -> Result<Subscription<'_, K, M, T, F>, SubscriptionError<K>> {
         // Note: Number of ids to listen to most likely affects the number of received events => number is added to channel bound
         // Addition instead of multiplication, because even distribution accross events is highly unlikely.
-        let (sender, receiver) = mpsc::sync_channel(ids.len() + self.subscription_channel_bound);
-        let channel_id = crate::uuid::Uuid::new_v4();
-        let subscription_sender = SubscriptionSender { channel_id, sender };
-
-        match self.subscriptions.write().ok() {
-            Some(mut locked_subs) => {
-                for id in ids.clone() {
-                    let entry = locked_subs.entry(id.clone());
-                    entry
-                        .and_modify(|v| {
-                            v.insert(subscription_sender.channel_id, subscription_sender.clone());
-                        })
-                        .or_insert({
-                            let mut h = HashMap::new();
-                            h.insert(subscription_sender.channel_id, subscription_sender.clone());
-                            h
-                        });
-                }
-            }
-            None => {
-                return Err(SubscriptionError::CouldNotAccessPublisher);
-            }
-        }
+        let (sender, receiver) = channel(ids.len() + self.subscription_channel_bound);
+        let core = self.add_subscription(
+            PublisherRef::Borrowed(self),
+            Some(ids),
+            false,
+            SubscriptionSender::new(EventSender::Sync(sender), None),
+        )?;
 
-        Ok(Subscription {
-            channel_id,
-            receiver,
-            sub_to_all: false,
-            subscriptions: Some(HashSet::from_iter(ids)),
-            publisher: self,
-        })
+        Ok(Subscription { core, receiver })
     }
 
     /// Returns a subscription to all events,
@@ -397,46 +1556,568 @@ where
     /// [req:subs.all]
     pub fn subscribe_to_all_events(
         &self,
-    ) -> Result<Subscription<K, M, T, F>, SubscriptionError<K>> {
-        let (sender, receiver) = mpsc::sync_channel(self.capture_channel_bound);
-        let channel_id = crate::uuid::Uuid::new_v4();
-
-        match self.any_event.write().ok() {
-            Some(mut locked_vec) => {
-                locked_vec.insert(channel_id, SubscriptionSender { channel_id, sender });
-            }
-            None => {
-                return Err(SubscriptionError::CouldNotAccessPublisher);
-            }
-        }
+    ) -> Result<Subscription<'_, K, M, T, F>, SubscriptionError<K>> {
+        let (sender, receiver) = channel(self.capture_channel_bound);
+        let core = self.add_subscription(
+            PublisherRef::Borrowed(self),
+            None,
+            false,
+            SubscriptionSender::new(EventSender::Sync(sender), None),
+        )?;
 
-        Ok(Subscription {
-            channel_id,
-            receiver,
-            sub_to_all: true,
-            subscriptions: None,
-            publisher: self,
-        })
+        Ok(Subscription { core, receiver })
     }
 
-    /// Returns `true` if capturing is *active*.
+    /// Returns a subscription to events with the given event-IDs that fulfill the given predicate,
+    /// or a [`SubscriptionError<K>`] if the subscription could not be created.
     ///
-    /// [req:cap.ctrl.info]
-    pub fn is_capturing(&self) -> bool {
-        self.capturing.load(Ordering::Acquire)
+    /// The predicate is evaluated by the publisher before an event is sent to the subscription,
+    /// so events not fulfilling the predicate do not fill up the subscription channel.
+    ///
+    /// [req:subs.specific.mult]
+    pub fn subscribe_where(
+        &self,
+        ids: Vec<K>,
+        predicate: impl Fn(&Event<K, M, T>) -> bool + Send + Sync + 'static,
+    ) -> Result<Subscription<'_, K, M, T, F>, SubscriptionError<K>> {
+        // Note: See `subscribe_to_many()` for the channel bound.
+        let (sender, receiver) = channel(ids.len() + self.subscription_channel_bound);
+        let core = self.add_subscription(
+            PublisherRef::Borrowed(self),
+            Some(ids),
+            false,
+            SubscriptionSender::new(EventSender::Sync(sender), Some(Arc::new(predicate))),
+        )?;
+
+        Ok(Subscription { core, receiver })
     }
 
-    /// Start capturing.
+    /// Returns a subscription to all events that fulfill the given predicate,
+    /// or a [`SubscriptionError<K>`] if the subscription could not be created.
     ///
-    /// **Note:** Capturing is already started initially, so this function is only needed after manually stopping capturing.
+    /// See [`subscribe_where`](Self::subscribe_where) for more information.
     ///
-    /// [req:cap.ctrl.start]
-    pub fn start(&self) {
-        let empty_msg: Option<M> = None;
-        let start_event = Event::new(EventEntry::new(K::start_id(), empty_msg, this_origin!()));
+    /// [req:subs.all]
+    pub fn subscribe_to_all_events_where(
+        &self,
+        predicate: impl Fn(&Event<K, M, T>) -> bool + Send + Sync + 'static,
+    ) -> Result<Subscription<'_, K, M, T, F>, SubscriptionError<K>> {
+        let (sender, receiver) = channel(self.capture_channel_bound);
+        let core = self.add_subscription(
+            PublisherRef::Borrowed(self),
+            None,
+            false,
+            SubscriptionSender::new(EventSender::Sync(sender), Some(Arc::new(predicate))),
+        )?;
+
+        Ok(Subscription { core, receiver })
+    }
+
+    /// Returns a subscription to events with the given event-IDs that uses the given [`OverflowPolicy`] if its channel is full,
+    /// or a [`SubscriptionError<K>`] if the subscription could not be created.
+    ///
+    /// [req:subs.specific.mult]
+    pub fn subscribe_with_policy(
+        &self,
+        ids: Vec<K>,
+        overflow_policy: OverflowPolicy,
+    ) -> Result<Subscription<'_, K, M, T, F>, SubscriptionError<K>> {
+        // Note: See `subscribe_to_many()` for the channel bound.
+        let (subscription_sender, receiver) =
+            Self::policy_channel(ids.len() + self.subscription_channel_bound, overflow_policy);
+        let core = self.add_subscription(
+            PublisherRef::Borrowed(self),
+            Some(ids),
+            false,
+            subscription_sender,
+        )?;
+
+        Ok(Subscription { core, receiver })
+    }
+
+    /// Returns a subscription to all events that uses the given [`OverflowPolicy`] if its channel is full,
+    /// or a [`SubscriptionError<K>`] if the subscription could not be created.
+    ///
+    /// [req:subs.all]
+    pub fn subscribe_to_all_events_with_policy(
+        &self,
+        overflow_policy: OverflowPolicy,
+    ) -> Result<Subscription<'_, K, M, T, F>, SubscriptionError<K>> {
+        let (subscription_sender, receiver) =
+            Self::policy_channel(self.capture_channel_bound, overflow_policy);
+        let core = self.add_subscription(
+            PublisherRef::Borrowed(self),
+            None,
+            false,
+            subscription_sender,
+        )?;
+
+        Ok(Subscription { core, receiver })
+    }
+
+    /// Creates the channel for a subscription with the given [`OverflowPolicy`].
+    fn policy_channel(
+        bound: usize,
+        overflow_policy: OverflowPolicy,
+    ) -> (SubscriptionSender<K, M, T>, EventReceiver<K, M, T>) {
+        let missed_events = Arc::new(AtomicUsize::new(0));
+
+        let (sender, receiver) = channel(bound);
+
+        (
+            SubscriptionSender::with_policy(
+                EventSender::Sync(sender),
+                None,
+                overflow_policy,
+                missed_events,
+            ),
+            receiver,
+        )
+    }
+
+    /// Marks the given event-ID as retained.
+    ///
+    /// The publisher keeps the last event of retained event-IDs,
+    /// and new subscriptions to a retained event-ID receive this event immediately.
+    ///
+    /// **Note:** Only events captured after the event-ID was marked are kept.
+    ///
+    /// [req:subs.specific]
+    pub fn mark_retained(&self, id: K) {
+        if let Ok(mut locked_buffer) = self.replay_buffer.lock() {
+            locked_buffer.last_events.entry(id).or_default();
+        }
+    }
+
+    /// Removes the retained mark of the given event-ID, and drops its last event.
+    ///
+    /// [req:subs.specific]
+    pub fn unmark_retained(&self, id: &K) {
+        if let Ok(mut locked_buffer) = self.replay_buffer.lock() {
+            locked_buffer.last_events.remove(id);
+        }
+    }
+
+    /// Returns `true` if the given event-ID is marked as retained.
+    ///
+    /// [req:subs.specific]
+    pub fn is_retained(&self, id: &K) -> bool {
+        self.replay_buffer
+            .lock()
+            .map(|locked_buffer| locked_buffer.last_events.contains_key(id))
+            .unwrap_or_default()
+    }
+
+    /// Returns the last event of the given retained event-ID,
+    /// or `None` if the event-ID is not marked as retained, or no event was received since it was marked.
+    ///
+    /// [req:subs.specific]
+    pub fn last_event(&self, id: &K) -> Option<Arc<Event<K, M, T>>> {
+        let locked_buffer = self.replay_buffer.lock().ok()?;
+        locked_buffer
+            .last_events
+            .get(id)?
+            .as_ref()
+            .map(|(_, event)| event.clone())
+    }
+
+    /// Sets the maximum number of events that are retained over all event-IDs to be replayed to late subscribers.
+    ///
+    /// Retained events are dropped oldest first if the capacity is exceeded.
+    /// Setting a capacity of `0` disables retaining events over all event-IDs, which is the default.
+    ///
+    /// [req:subs]
+    pub fn set_replay_capacity(&self, capacity: usize) {
+        if let Ok(mut locked_buffer) = self.replay_buffer.lock() {
+            locked_buffer.set_capacity(capacity);
+        }
+    }
+
+    /// Sets the maximum number of events that are retained per event-ID to be replayed to late subscribers.
+    ///
+    /// Retained events are dropped oldest first if the capacity is exceeded.
+    /// Setting a capacity of `0` disables retaining events per event-ID, which is the default.
+    ///
+    /// [req:subs]
+    pub fn set_replay_capacity_per_id(&self, capacity_per_id: usize) {
+        if let Ok(mut locked_buffer) = self.replay_buffer.lock() {
+            locked_buffer.set_capacity_per_id(capacity_per_id);
+        }
+    }
+
+    /// Returns a subscription to events with the given event-IDs that first receives up to `n` of the latest retained events with these event-IDs,
+    /// or a [`SubscriptionError<K>`] if the subscription could not be created.
+    ///
+    /// Retained events are received in the order they were captured, followed by live events without gaps or duplicates.
+    ///
+    /// **Note:** Events are only retained if a replay capacity is set.
+    /// See [`set_replay_capacity`](Self::set_replay_capacity) and [`set_replay_capacity_per_id`](Self::set_replay_capacity_per_id).
+    ///
+    /// [req:subs.specific.mult]
+    pub fn subscribe_with_replay(
+        &self,
+        ids: Vec<K>,
+        n: usize,
+    ) -> Result<Subscription<'_, K, M, T, F>, SubscriptionError<K>> {
+        self.replay_subscription(Some(ids), n)
+    }
+
+    /// Returns a subscription to all events that first receives up to `n` of the latest retained events,
+    /// or a [`SubscriptionError<K>`] if the subscription could not be created.
+    ///
+    /// See [`subscribe_with_replay`](Self::subscribe_with_replay) for more information.
+    ///
+    /// [req:subs.all]
+    pub fn subscribe_to_all_events_with_replay(
+        &self,
+        n: usize,
+    ) -> Result<Subscription<'_, K, M, T, F>, SubscriptionError<K>> {
+        self.replay_subscription(None, n)
+    }
+
+    /// Creates a subscription that first receives up to `n` of the latest retained events.
+    fn replay_subscription(
+        &self,
+        ids: Option<Vec<K>>,
+        n: usize,
+    ) -> Result<Subscription<'_, K, M, T, F>, SubscriptionError<K>> {
+        let bound = match &ids {
+            // See `subscribe_to_many()` for the channel bound.
+            Some(ids) => ids.len() + self.subscription_channel_bound,
+            None => self.capture_channel_bound,
+        };
+        let (sender, receiver) = channel(bound);
 
-        let _ = self.capturer.send(start_event);
+        let core = self.insert_subscription(
+            PublisherRef::Borrowed(self),
+            ids,
+            false,
+            SubscriptionSender::new(EventSender::Sync(sender), None),
+            n,
+        )?;
+
+        Ok(Subscription { core, receiver })
+    }
+
+    /// Returns all subscriptions that missed events, because their channel was full.
+    ///
+    /// [req:subs]
+    pub fn get_lagging_subscriptions(&self) -> Vec<LaggingSubscription> {
+        let mut lagging: HashMap<crate::uuid::Uuid, usize> = HashMap::new();
+        let mut add_lagging = |sub_sender: &SubscriptionSender<K, M, T>| {
+            let missed_events = sub_sender.missed_events.load(Ordering::Relaxed);
+            if missed_events > 0 {
+                lagging.insert(sub_sender.channel_id, missed_events);
+            }
+        };
+
+        for id_subscriptions in [&self.subscriptions, &self.prefix_subscriptions] {
+            if let Ok(locked_subs) = id_subscriptions.read() {
+                for sub_senders in locked_subs.values() {
+                    sub_senders.values().for_each(&mut add_lagging);
+                }
+            }
+        }
+
+        if let Ok(locked_vec) = self.any_event.read() {
+            locked_vec.values().for_each(&mut add_lagging);
+        }
+
+        lagging
+            .into_iter()
+            .map(|(channel_id, missed_events)| LaggingSubscription {
+                channel_id,
+                missed_events,
+            })
+            .collect()
+    }
+
+    /// Returns a subscription to events with the given event-ID, or any of its descendants,
+    /// or a [`SubscriptionError<K>`] if the subscription could not be created.
+    ///
+    /// **Note:** Only available for [`HierarchicalId`]s.
+    ///
+    /// [req:subs.specific.one]
+    pub fn subscribe_prefix(
+        &self,
+        id: K,
+    ) -> Result<Subscription<'_, K, M, T, F>, SubscriptionError<K>>
+    where
+        K: HierarchicalId,
+    {
+        self.subscribe_to_many_prefixes(vec![id])
+    }
+
+    /// Returns a subscription to events with the given event-IDs, or any of their descendants,
+    /// or a [`SubscriptionError<K>`] if the subscription could not be created.
+    ///
+    /// Events are only received once, even if multiple ancestors of the event-ID are subscribed.
+    ///
+    /// **Note:** Only available for [`HierarchicalId`]s.
+    ///
+    /// [req:subs.specific.mult]
+    pub fn subscribe_to_many_prefixes(
+        &self,
+        ids: Vec<K>,
+    ) -> Result<Subscription<'_, K, M, T, F>, SubscriptionError<K>>
+    where
+        K: HierarchicalId,
+    {
+        let _ = self.parent_of.get_or_init(|| <K as HierarchicalId>::parent);
+
+        // Note: See `subscribe_to_many()` for the channel bound.
+        let (sender, receiver) = channel(ids.len() + self.subscription_channel_bound);
+        let core = self.add_subscription(
+            PublisherRef::Borrowed(self),
+            Some(ids),
+            true,
+            SubscriptionSender::new(EventSender::Sync(sender), None),
+        )?;
+
+        Ok(Subscription { core, receiver })
+    }
+
+    /// Returns an owned subscription to events with the given event-ID,
+    /// or a [`SubscriptionError<K>`] if the subscription could not be created.
+    ///
+    /// [req:subs.specific.one]
+    pub fn subscribe_owned(
+        self: &Arc<Self>,
+        id: K,
+    ) -> Result<OwnedSubscription<K, M, T, F>, SubscriptionError<K>>
+    where
+        F: 'static,
+    {
+        self.subscribe_to_many_owned(vec![id])
+    }
+
+    /// Returns an owned subscription to events with the given event-IDs,
+    /// or a [`SubscriptionError<K>`] if the subscription could not be created.
+    ///
+    /// [req:subs.specific.mult]
+    pub fn subscribe_to_many_owned(
+        self: &Arc<Self>,
+        ids: Vec<K>,
+    ) -> Result<OwnedSubscription<K, M, T, F>, SubscriptionError<K>>
+    where
+        F: 'static,
+    {
+        // Note: See `subscribe_to_many()` for the channel bound.
+        let (sender, receiver) = channel(ids.len() + self.subscription_channel_bound);
+        let core = self.add_subscription(
+            PublisherRef::Owned(self.clone()),
+            Some(ids),
+            false,
+            SubscriptionSender::new(EventSender::Sync(sender), None),
+        )?;
+
+        Ok(OwnedSubscription { core, receiver })
+    }
+
+    /// Returns an owned subscription to all events,
+    /// or a [`SubscriptionError<K>`] if the subscription could not be created.
+    ///
+    /// [req:subs.all]
+    pub fn subscribe_to_all_events_owned(
+        self: &Arc<Self>,
+    ) -> Result<OwnedSubscription<K, M, T, F>, SubscriptionError<K>>
+    where
+        F: 'static,
+    {
+        let (sender, receiver) = channel(self.capture_channel_bound);
+        let core = self.add_subscription(
+            PublisherRef::Owned(self.clone()),
+            None,
+            false,
+            SubscriptionSender::new(EventSender::Sync(sender), None),
+        )?;
+
+        Ok(OwnedSubscription { core, receiver })
+    }
+
+    /// Returns an async subscription to events with the given event-ID,
+    /// or a [`SubscriptionError<K>`] if the subscription could not be created.
+    ///
+    /// [req:subs.specific.one]
+    #[cfg(feature = "async")]
+    pub fn subscribe_async(
+        &self,
+        id: K,
+    ) -> Result<AsyncSubscription<'_, K, M, T, F>, SubscriptionError<K>> {
+        self.subscribe_to_many_async(vec![id])
+    }
+
+    /// Returns an async subscription to events with the given event-IDs,
+    /// or a [`SubscriptionError<K>`] if the subscription could not be created.
+    ///
+    /// [req:subs.specific.mult]
+    #[cfg(feature = "async")]
+    pub fn subscribe_to_many_async(
+        &self,
+        ids: Vec<K>,
+    ) -> Result<AsyncSubscription<'_, K, M, T, F>, SubscriptionError<K>> {
+        // Note: See `subscribe_to_many()` for the channel bound.
+        let (sender, receiver) =
+            futures::channel::mpsc::channel(ids.len() + self.subscription_channel_bound);
+        let core = self.add_subscription(
+            PublisherRef::Borrowed(self),
+            Some(ids),
+            false,
+            SubscriptionSender::new(EventSender::Async(Arc::new(Mutex::new(sender))), None),
+        )?;
+
+        Ok(AsyncSubscription { core, receiver })
+    }
+
+    /// Returns an async subscription to all events,
+    /// or a [`SubscriptionError<K>`] if the subscription could not be created.
+    ///
+    /// [req:subs.all]
+    #[cfg(feature = "async")]
+    pub fn subscribe_to_all_events_async(
+        &self,
+    ) -> Result<AsyncSubscription<'_, K, M, T, F>, SubscriptionError<K>> {
+        let (sender, receiver) = futures::channel::mpsc::channel(self.capture_channel_bound);
+        let core = self.add_subscription(
+            PublisherRef::Borrowed(self),
+            None,
+            false,
+            SubscriptionSender::new(EventSender::Async(Arc::new(Mutex::new(sender))), None),
+        )?;
+
+        Ok(AsyncSubscription { core, receiver })
+    }
+
+    /// Stores the *sender-part* of a new subscription channel for the given event-IDs,
+    /// or for all events if no event-IDs are given.
+    ///
+    /// If `prefix` is `true`, the subscription also receives events of all descendants of the given event-IDs.
+    ///
+    /// The last events of retained event-IDs are sent to the new subscription channel.
+    fn add_subscription<'p>(
+        &self,
+        publisher: PublisherRef<'p, K, M, T, F>,
+        ids: Option<Vec<K>>,
+        prefix: bool,
+        subscription_sender: SubscriptionSender<K, M, T>,
+    ) -> Result<SubscriptionCore<'p, K, M, T, F>, SubscriptionError<K>> {
+        self.insert_subscription(publisher, ids, prefix, subscription_sender, 0)
+    }
 
+    /// Stores the *sender-part* of a new subscription channel for the given event-IDs,
+    /// or for all events if no event-IDs are given.
+    ///
+    /// Up to `replay` of the latest retained events, and the last events of retained event-IDs
+    /// are sent to the new subscription channel before any live event.
+    fn insert_subscription<'p>(
+        &self,
+        publisher: PublisherRef<'p, K, M, T, F>,
+        ids: Option<Vec<K>>,
+        prefix: bool,
+        subscription_sender: SubscriptionSender<K, M, T>,
+        replay: usize,
+    ) -> Result<SubscriptionCore<'p, K, M, T, F>, SubscriptionError<K>> {
+        if self.is_shut_down() {
+            return Err(SubscriptionError::PublisherShutDown);
+        }
+
+        // Note: The subscription skips all live events until its start sequence number is set below.
+        subscription_sender
+            .start_seq
+            .store(u64::MAX, Ordering::Release);
+
+        let channel_id = subscription_sender.channel_id;
+        let missed_events = subscription_sender.missed_events.clone();
+
+        let core = match &ids {
+            Some(ids) => {
+                let id_subscriptions = if prefix {
+                    &self.prefix_subscriptions
+                } else {
+                    &self.subscriptions
+                };
+
+                match id_subscriptions.write().ok() {
+                    Some(mut locked_subs) => {
+                        for id in ids.iter() {
+                            locked_subs
+                                .entry(id.clone())
+                                .or_default()
+                                .insert(channel_id, subscription_sender.clone());
+                        }
+                    }
+                    None => {
+                        return Err(SubscriptionError::CouldNotAccessPublisher);
+                    }
+                }
+
+                SubscriptionCore {
+                    channel_id,
+                    sub_to_all: false,
+                    prefix,
+                    subscriptions: Some(HashSet::from_iter(ids.iter().cloned())),
+                    publisher,
+                    missed_events,
+                }
+            }
+            None => {
+                match self.any_event.write().ok() {
+                    Some(mut locked_vec) => {
+                        locked_vec.insert(channel_id, subscription_sender.clone());
+                    }
+                    None => {
+                        return Err(SubscriptionError::CouldNotAccessPublisher);
+                    }
+                }
+
+                SubscriptionCore {
+                    channel_id,
+                    sub_to_all: true,
+                    prefix: false,
+                    subscriptions: None,
+                    publisher,
+                    missed_events,
+                }
+            }
+        };
+
+        // Note: Events retained before the start sequence number are replayed, all later events are forwarded live.
+        // The lock is only held to take the snapshot, so forwarding events never waits on new subscriptions.
+        let locked_buffer = match self.replay_buffer.lock() {
+            Ok(locked_buffer) => locked_buffer,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        for event in locked_buffer.latest(ids.as_deref(), replay) {
+            subscription_sender.send_initial(event);
+        }
+        subscription_sender
+            .start_seq
+            .store(locked_buffer.next_seq, Ordering::Release);
+        drop(locked_buffer);
+
+        Ok(core)
+    }
+
+    /// Returns `true` if capturing is *active*.
+    ///
+    /// [req:cap.ctrl.info]
+    pub fn is_capturing(&self) -> bool {
+        self.capturing.load(Ordering::Acquire)
+    }
+
+    /// Start capturing.
+    ///
+    /// **Note:** Capturing is already started initially, so this function is only needed after manually stopping capturing.
+    ///
+    /// **Note:** Capturing cannot be restarted after the publisher was shut down.
+    ///
+    /// [req:cap.ctrl.start]
+    pub fn start(&self) {
+        if self.is_shut_down() {
+            return;
+        }
+
+        self.send_control_event(K::start_id(), None);
         self.capturing.store(true, Ordering::Release);
     }
 
@@ -444,12 +2125,187 @@ where
     ///
     /// [req:cap.ctrl.stop]
     pub fn stop(&self) {
+        self.send_control_event(K::stop_id(), None);
+        self.capturing.store(false, Ordering::Release);
+    }
+
+    /// Sends a control event with the given ID to the event handler.
+    ///
+    /// `signaled_id` is the event-ID that was disabled or enabled, if the control event is a signal event.
+    fn send_control_event(&self, control_id: K, signaled_id: Option<K>) {
         let empty_msg: Option<M> = None;
-        let stop_event = Event::new(EventEntry::new(K::stop_id(), empty_msg, this_origin!()));
+        let mut control_event = Event::new(EventEntry::new(control_id, empty_msg, this_origin!()));
+        control_event.signaled_id = signaled_id;
 
-        let _ = self.capturer.send(stop_event);
+        if self.capturer.send(CaptureMsg::Event(control_event)).is_ok() {
+            self.stats.captured.fetch_add(1, Ordering::Relaxed);
+        }
+    }
 
-        self.capturing.store(false, Ordering::Release);
+    /// Returns `true` if events with the given event-ID may be captured.
+    ///
+    /// **Note:** Control-IDs are always enabled.
+    ///
+    /// [req:cap.ctrl.info]
+    pub fn is_id_enabled(&self, id: &K) -> bool {
+        match self.disabled_ids.read() {
+            Ok(locked_ids) => !locked_ids.contains(id),
+            Err(poisoned) => !poisoned.into_inner().contains(id),
+        }
+    }
+
+    /// Returns all disabled event-IDs.
+    ///
+    /// [req:cap.ctrl.info]
+    pub fn disabled_ids(&self) -> Vec<K> {
+        match self.disabled_ids.read() {
+            Ok(locked_ids) => locked_ids.iter().cloned().collect(),
+            Err(poisoned) => poisoned.into_inner().iter().cloned().collect(),
+        }
+    }
+
+    /// Stops capturing events with the given event-ID, while events of other IDs are still captured.
+    ///
+    /// The disabled ID is checked before the filter is applied.
+    /// The control event of [`CaptureControl::disabled_signal_id`] is sent if the ID was enabled before.
+    /// The disabled ID is available via [`Event::get_signaled_id`] of the control event.
+    ///
+    /// Returns `true` if the ID was enabled before.
+    ///
+    /// **Note:** Control-IDs cannot be disabled.
+    ///
+    /// [req:cap.ctrl]
+    pub fn disable_id(&self, id: &K) -> bool {
+        if is_control_id(id) {
+            return false;
+        }
+
+        let disabled = match self.disabled_ids.write() {
+            Ok(mut locked_ids) => locked_ids.insert(id.clone()),
+            Err(poisoned) => poisoned.into_inner().insert(id.clone()),
+        };
+
+        if disabled {
+            if let Some(signal_id) = K::disabled_signal_id(id).filter(K::is_signal_id) {
+                self.send_control_event(signal_id, Some(id.clone()));
+            }
+        }
+        disabled
+    }
+
+    /// Starts capturing events with the given event-ID again.
+    ///
+    /// The control event of [`CaptureControl::enabled_signal_id`] is sent if the ID was disabled before.
+    /// The enabled ID is available via [`Event::get_signaled_id`] of the control event.
+    ///
+    /// Returns `true` if the ID was disabled before.
+    ///
+    /// [req:cap.ctrl]
+    pub fn enable_id(&self, id: &K) -> bool {
+        let enabled = match self.disabled_ids.write() {
+            Ok(mut locked_ids) => locked_ids.remove(id),
+            Err(poisoned) => poisoned.into_inner().remove(id),
+        };
+
+        if enabled {
+            if let Some(signal_id) = K::enabled_signal_id(id).filter(K::is_signal_id) {
+                self.send_control_event(signal_id, Some(id.clone()));
+            }
+        }
+        enabled
+    }
+
+    /// Stops capturing events with any of the given event-IDs.
+    ///
+    /// Returns the number of IDs that were enabled before.
+    ///
+    /// See [`disable_id`](Self::disable_id) for more information.
+    ///
+    /// [req:cap.ctrl]
+    pub fn disable_ids<'a>(&self, ids: impl IntoIterator<Item = &'a K>) -> usize {
+        ids.into_iter().filter(|id| self.disable_id(id)).count()
+    }
+
+    /// Starts capturing events with any of the given event-IDs again.
+    ///
+    /// Returns the number of IDs that were disabled before.
+    ///
+    /// See [`enable_id`](Self::enable_id) for more information.
+    ///
+    /// [req:cap.ctrl]
+    pub fn enable_ids<'a>(&self, ids: impl IntoIterator<Item = &'a K>) -> usize {
+        ids.into_iter().filter(|id| self.enable_id(id)).count()
+    }
+
+    /// Starts capturing events of all disabled event-IDs again.
+    ///
+    /// Returns the number of IDs that were disabled before.
+    ///
+    /// [req:cap.ctrl]
+    pub fn enable_all_ids(&self) -> usize {
+        let disabled_ids = self.disabled_ids();
+        self.enable_ids(disabled_ids.iter())
+    }
+
+    /// Blocks until all events that were captured before this call have been forwarded to the event handler.
+    ///
+    /// Returns immediately if the publisher was already shut down.
+    ///
+    /// **Note:** This function must **not** be called inside the event handler, because the handler would wait on itself.
+    ///
+    /// [req:cap]
+    pub fn flush(&self) {
+        let (done_send, done_recv) = mpsc::sync_channel(1);
+
+        if self.capturer.send(CaptureMsg::Flush(done_send)).is_ok() {
+            // Note: Receiving fails if the event handler thread stopped before handling the flush message.
+            let _ = done_recv.recv();
+        }
+    }
+
+    /// Stops capturing, forwards all events captured before this call to the event handler,
+    /// and closes all subscription channels afterwards.
+    ///
+    /// Receivers of existing subscriptions are disconnected once all remaining events were received,
+    /// and new subscriptions cannot be created anymore.
+    ///
+    /// **Note:** This function must **not** be called inside the event handler, because the handler would wait on itself.
+    ///
+    /// [req:cap.ctrl.stop]
+    pub fn shutdown(&self) {
+        if self.shut_down.swap(true, Ordering::AcqRel) {
+            return;
+        }
+
+        self.stop();
+        let _ = self.capturer.send(CaptureMsg::Shutdown);
+
+        if let Ok(mut capture_thread) = self.capture_thread.lock() {
+            if let Some(handle) = capture_thread.take() {
+                let _ = handle.join();
+            }
+        }
+
+        // Dropping all *sender-parts* closes the subscription channels.
+        if let Ok(mut locked_subscriptions) = self.subscriptions.write() {
+            locked_subscriptions.clear();
+        }
+        if let Ok(mut locked_prefix_subs) = self.prefix_subscriptions.write() {
+            locked_prefix_subs.clear();
+        }
+        if let Ok(mut locked_vec) = self.any_event.write() {
+            locked_vec.clear();
+        }
+
+        // Dropping all waiters notifies them that no more events will be dispatched.
+        if let Ok(mut locked_waiters) = self.dispatch_waiters.lock() {
+            locked_waiters.clear();
+        }
+    }
+
+    /// Returns `true` if the publisher was shut down.
+    pub fn is_shut_down(&self) -> bool {
+        self.shut_down.load(Ordering::Acquire)
     }
 
     /// Send the given event to all subscriber of the event.
@@ -462,46 +2318,68 @@ where
         let arc_event = Arc::new(event);
         let key = arc_event.entry.get_event_id();
 
+        // Note: The lock is released before forwarding, so a full subscription channel does not block access to retained events.
+        // Subscriptions added after the event was retained skip it, because they already received it as retained event.
+        let seq = match self.replay_buffer.lock() {
+            Ok(mut locked_buffer) => locked_buffer.retain(&arc_event),
+            Err(poisoned) => poisoned.into_inner().retain(&arc_event),
+        };
+
         let mut bad_subs: Vec<crate::uuid::Uuid> = Vec::new();
+        let mut bad_prefix_subs: Vec<(K, crate::uuid::Uuid)> = Vec::new();
         let mut bad_any_event: Vec<crate::uuid::Uuid> = Vec::new();
 
         if let Ok(locked_subscriptions) = self.subscriptions.read() {
             if let Some(sub_senders) = locked_subscriptions.get(key) {
                 for (channel_id, sub_sender) in sub_senders.iter() {
-                    let bad_channel = if self.capture_blocking.load(Ordering::Acquire) {
-                        sub_sender.sender.send(arc_event.clone()).is_err()
-                    } else {
-                        matches!(
-                            sub_sender.sender.try_send(arc_event.clone()),
-                            Err(TrySendError::Disconnected(_))
-                        )
-                    };
-
-                    if bad_channel {
+                    if !self.forward_event(sub_sender, arc_event.clone(), seq) {
                         bad_subs.push(*channel_id);
                     }
                 }
             }
         }
 
+        // Note: Parent function is only set once a prefix subscription was created.
+        if let Some(parent_of) = self.parent_of.get() {
+            if let Ok(locked_prefix_subs) = self.prefix_subscriptions.read() {
+                if !locked_prefix_subs.is_empty() {
+                    // Subscriptions to multiple ancestors of the event-ID must only receive the event once.
+                    let mut forwarded: HashSet<crate::uuid::Uuid> = HashSet::new();
+                    let mut prefix = Some(key.clone());
+
+                    while let Some(prefix_id) = prefix {
+                        if let Some(sub_senders) = locked_prefix_subs.get(&prefix_id) {
+                            for (channel_id, sub_sender) in sub_senders.iter() {
+                                if forwarded.insert(*channel_id)
+                                    && !self.forward_event(sub_sender, arc_event.clone(), seq)
+                                {
+                                    bad_prefix_subs.push((prefix_id.clone(), *channel_id));
+                                }
+                            }
+                        }
+
+                        prefix = parent_of(&prefix_id);
+                    }
+                }
+            }
+        }
+
         if let Ok(locked_vec) = self.any_event.read() {
             for (channel_id, any_event_sender) in locked_vec.iter() {
-                let bad_channel = if self.capture_blocking.load(Ordering::Acquire) {
-                    any_event_sender.sender.send(arc_event.clone()).is_err()
-                } else {
-                    matches!(
-                        any_event_sender.sender.try_send(arc_event.clone()),
-                        Err(TrySendError::Disconnected(_))
-                    )
-                };
-
-                if bad_channel {
+                if !self.forward_event(any_event_sender, arc_event.clone(), seq) {
                     bad_any_event.push(*channel_id);
                 }
             }
         }
 
         // Remove dead channels
+        let nr_bad_channels = bad_subs.len() + bad_prefix_subs.len() + bad_any_event.len();
+        if nr_bad_channels > 0 {
+            self.stats
+                .dead_channels_removed
+                .fetch_add(nr_bad_channels, Ordering::Relaxed);
+        }
+
         if !bad_subs.is_empty() {
             if let Ok(mut locked_subscriptions) = self.subscriptions.write() {
                 let mut entry = locked_subscriptions.entry(key.clone());
@@ -513,6 +2391,16 @@ where
             }
         }
 
+        if !bad_prefix_subs.is_empty() {
+            if let Ok(mut locked_prefix_subs) = self.prefix_subscriptions.write() {
+                for (prefix_id, i) in bad_prefix_subs {
+                    if let Some(sub_senders) = locked_prefix_subs.get_mut(&prefix_id) {
+                        sub_senders.remove(&i);
+                    }
+                }
+            }
+        }
+
         if !bad_any_event.is_empty() {
             if let Ok(mut locked_vec) = self.any_event.write() {
                 for i in bad_any_event {
@@ -520,5 +2408,26 @@ where
                 }
             }
         }
+
+        self.notify_dispatch_waiter(arc_event.get_entry_id(), true);
+    }
+
+    /// Forwards the event to the given subscription channel depending on the capture mode,
+    /// and the [`OverflowPolicy`] of the subscription.
+    ///
+    /// Events with a sequence number before the start of the subscription are skipped.
+    ///
+    /// Returns `false` if the subscription channel is disconnected.
+    fn forward_event(
+        &self,
+        sub_sender: &SubscriptionSender<K, M, T>,
+        event: Arc<Event<K, M, T>>,
+        seq: u64,
+    ) -> bool {
+        if seq < sub_sender.start_seq.load(Ordering::Acquire) {
+            return true;
+        }
+
+        sub_sender.forward(event, self.capture_blocking.load(Ordering::Acquire))
     }
 }
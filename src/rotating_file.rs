@@ -0,0 +1,394 @@
+//! Contains the [`RotatingFileSink`] that writes received events as JSON Lines into rotating files.
+//!
+//! The current file is located at `<directory>/<file_name>`.
+//! Rotated files are named `<file_name>.1` (newest) up to `<file_name>.<max_rotated_files>` (oldest),
+//! with an additional `.gz` extension if they are compressed.
+//!
+//! See [`jsonl`](crate::jsonl) for the format of written events.
+//!
+//! [req:subs]
+
+use std::{
+    convert::Infallible,
+    fs::{File, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use serde::Serialize;
+
+use crate::{
+    channel::Receiver,
+    event::{entry::EventEntry, filter::Filter, Event, Id, Msg},
+    jsonl::write_line,
+    publisher::CaptureControl,
+    subscription::{OwnedSubscription, Subscription},
+    worker::{consume_events, EventWorker},
+};
+
+/// Configuration of a [`RotatingFileSink`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RotationConfig {
+    /// Directory the files are written to.
+    pub directory: PathBuf,
+
+    /// Name of the current file.
+    pub file_name: String,
+
+    /// The current file is rotated once it exceeds this size in bytes.
+    ///
+    /// `None` disables size-based rotation.
+    pub max_file_size: Option<u64>,
+
+    /// The current file is rotated once it was written to for this duration.
+    ///
+    /// `None` disables time-based rotation.
+    pub max_file_age: Option<Duration>,
+
+    /// Maximum number of rotated files to keep. Older files are deleted.
+    pub max_rotated_files: usize,
+
+    /// Set to `true` to compress rotated files using gzip.
+    pub gzip: bool,
+
+    /// Maximum duration written events are buffered before they are flushed to the current file.
+    pub flush_interval: Duration,
+}
+
+impl RotationConfig {
+    /// Creates a new [`RotationConfig`] for the given directory and file name.
+    ///
+    /// Files are rotated once they exceed 10 MiB, up to 5 rotated files are kept uncompressed,
+    /// and written events are flushed every second.
+    pub fn new(directory: impl Into<PathBuf>, file_name: impl Into<String>) -> Self {
+        RotationConfig {
+            directory: directory.into(),
+            file_name: file_name.into(),
+            max_file_size: Some(10 * 1024 * 1024),
+            max_file_age: None,
+            max_rotated_files: 5,
+            gzip: false,
+            flush_interval: Duration::from_secs(1),
+        }
+    }
+
+    /// Returns the path of the current file.
+    pub fn current_path(&self) -> PathBuf {
+        self.directory.join(&self.file_name)
+    }
+
+    /// Returns the path of the rotated file at the given position (`1` being the newest).
+    pub fn rotated_path(&self, position: usize, gzip: bool) -> PathBuf {
+        let extension = if gzip { ".gz" } else { "" };
+        self.directory
+            .join(format!("{}.{}{}", self.file_name, position, extension))
+    }
+}
+
+/// Consumer that writes every event received by an all-events subscription as JSON Lines into rotating files.
+///
+/// Events are written by a separate thread that takes ownership of the subscription.
+/// Buffered events are flushed once the flush interval elapsed, and on every stop event (see [`CaptureControl::stop`]).
+///
+/// Failing writes (e.g. if the disk is full) do not stop the sink.
+/// Instead, the number of events that could not be written is counted as dropped writes.
+///
+/// The writer thread stops once [`stop`](Self::stop) is called, the sink is dropped, or the publisher is shut down.
+pub struct RotatingFileSink {
+    worker: EventWorker<()>,
+    dropped_writes: Arc<AtomicUsize>,
+}
+
+impl RotatingFileSink {
+    /// Writes all events received by the given subscription of a static publisher into rotating files.
+    ///
+    /// Returns an error if the current file could not be opened.
+    pub fn spawn<K, M, T, F>(
+        subscription: Subscription<'static, K, M, T, F>,
+        config: RotationConfig,
+    ) -> std::io::Result<Self>
+    where
+        K: Id + CaptureControl + Serialize,
+        M: Msg + Serialize,
+        T: EventEntry<K, M>,
+        F: Filter<K, M> + Send + Sync,
+    {
+        let files = RotatingFiles::open(config)?;
+
+        Ok(Self::start(
+            move |stop: &AtomicBool, dropped: &AtomicUsize| {
+                write_events(subscription.get_receiver(), files, stop, dropped)
+            },
+        ))
+    }
+
+    /// Writes all events received by the given owned subscription into rotating files.
+    ///
+    /// Returns an error if the current file could not be opened.
+    pub fn spawn_owned<K, M, T, F>(
+        subscription: OwnedSubscription<K, M, T, F>,
+        config: RotationConfig,
+    ) -> std::io::Result<Self>
+    where
+        K: Id + CaptureControl + Serialize,
+        M: Msg + Serialize,
+        T: EventEntry<K, M>,
+        F: Filter<K, M> + Send + Sync + 'static,
+    {
+        let files = RotatingFiles::open(config)?;
+
+        Ok(Self::start(
+            move |stop: &AtomicBool, dropped: &AtomicUsize| {
+                write_events(subscription.get_receiver(), files, stop, dropped)
+            },
+        ))
+    }
+
+    fn start(write_fn: impl FnOnce(&AtomicBool, &AtomicUsize) + Send + 'static) -> Self {
+        let dropped_writes = Arc::new(AtomicUsize::new(0));
+        let thread_dropped = dropped_writes.clone();
+
+        RotatingFileSink {
+            worker: EventWorker::spawn(move |stop: &AtomicBool| write_fn(stop, &thread_dropped)),
+            dropped_writes,
+        }
+    }
+
+    /// Returns the number of events that could not be written.
+    pub fn dropped_writes(&self) -> usize {
+        self.dropped_writes.load(Ordering::Relaxed)
+    }
+
+    /// Stops the writer thread once all events in the subscription channel are written and flushed,
+    /// and returns the number of events that could not be written.
+    ///
+    /// **Note:** Use `flush()` of the publisher beforehand to make sure all captured events reached the subscription channel.
+    pub fn stop(self) -> usize {
+        let _ = self.worker.stop();
+        self.dropped_writes.load(Ordering::Relaxed)
+    }
+}
+
+/// Writes events of the given receiver into rotating files, until `stop` is set or the channel is disconnected.
+fn write_events<K, M, T>(
+    receiver: &Receiver<Arc<Event<K, M, T>>>,
+    mut files: RotatingFiles,
+    stop: &AtomicBool,
+    dropped: &AtomicUsize,
+) where
+    K: Id + CaptureControl + Serialize,
+    M: Msg + Serialize,
+    T: EventEntry<K, M>,
+{
+    let _ = consume_events::<_, _, Infallible>(
+        receiver,
+        stop,
+        &mut files,
+        |files, event| {
+            files.write(&event, dropped);
+
+            // [req:cap.ctrl.stop]
+            if CaptureControl::stop(event.get_event_id()) {
+                files.flush(dropped);
+            }
+            files.flush_if_due(dropped);
+            Ok(())
+        },
+        |files| {
+            files.flush_if_due(dropped);
+            Ok(())
+        },
+    );
+
+    files.flush(dropped);
+}
+
+/// The current file, and its rotation state.
+struct RotatingFiles {
+    config: RotationConfig,
+    file: File,
+
+    /// Encoded events that were not yet written to the current file.
+    buffer: Vec<u8>,
+    /// Number of events in the buffer.
+    buffered_events: usize,
+
+    /// Number of bytes written to the current file.
+    file_size: u64,
+    /// Point in time the current file was opened.
+    opened_at: Instant,
+    /// Point in time buffered events were last written to the current file.
+    last_flush: Instant,
+}
+
+impl RotatingFiles {
+    fn open(config: RotationConfig) -> std::io::Result<Self> {
+        std::fs::create_dir_all(&config.directory)?;
+        let file = open_append(&config.current_path())?;
+        let file_size = file.metadata()?.len();
+
+        Ok(RotatingFiles {
+            config,
+            file,
+            buffer: Vec::new(),
+            buffered_events: 0,
+            file_size,
+            opened_at: Instant::now(),
+            last_flush: Instant::now(),
+        })
+    }
+
+    /// Buffers the given event, and rotates the current file if it exceeds the maximum size.
+    fn write<K, M, T>(&mut self, event: &Event<K, M, T>, dropped: &AtomicUsize)
+    where
+        K: Id + Serialize,
+        M: Msg + Serialize,
+        T: EventEntry<K, M>,
+    {
+        if write_line(&mut self.buffer, event).is_err() {
+            add_dropped(dropped, 1);
+            return;
+        }
+        self.buffered_events += 1;
+
+        if let Some(max_file_size) = self.config.max_file_size {
+            if self.file_size + self.buffer.len() as u64 >= max_file_size {
+                self.flush(dropped);
+                self.rotate();
+            }
+        }
+    }
+
+    /// Writes all buffered events to the current file if the flush interval elapsed,
+    /// and rotates the current file if it exceeds the maximum file age.
+    fn flush_if_due(&mut self, dropped: &AtomicUsize) {
+        if self.last_flush.elapsed() >= self.config.flush_interval {
+            self.flush(dropped);
+        }
+        self.rotate_if_needed(dropped);
+    }
+
+    /// Writes all buffered events to the current file.
+    ///
+    /// **Note:** Buffered events are counted as dropped if they could not be written.
+    fn flush(&mut self, dropped: &AtomicUsize) {
+        self.last_flush = Instant::now();
+
+        if self.buffer.is_empty() {
+            return;
+        }
+
+        match self
+            .file
+            .write_all(&self.buffer)
+            .and_then(|_| self.file.flush())
+        {
+            Ok(()) => {
+                self.file_size += self.buffer.len() as u64;
+            }
+            Err(_) => {
+                add_dropped(dropped, self.buffered_events);
+            }
+        }
+
+        self.buffer.clear();
+        self.buffered_events = 0;
+    }
+
+    /// Rotates the current file if it was written to for longer than the maximum file age.
+    fn rotate_if_needed(&mut self, dropped: &AtomicUsize) {
+        if let Some(max_file_age) = self.config.max_file_age {
+            if self.opened_at.elapsed() >= max_file_age {
+                self.flush(dropped);
+                self.rotate();
+            }
+        }
+    }
+
+    /// Moves the current file to the first rotated position, and opens a new current file.
+    ///
+    /// **Note:** Empty files are not rotated.
+    /// If the current file cannot be moved, events are still appended to it, and rotation is retried on the next write.
+    fn rotate(&mut self) {
+        self.opened_at = Instant::now();
+        if self.file_size == 0 {
+            return;
+        }
+
+        let _ = self.shift_rotated_files();
+
+        let current_path = self.config.current_path();
+        let rotated_path = self.config.rotated_path(1, false);
+        let moved = if self.config.max_rotated_files == 0 {
+            std::fs::remove_file(&current_path).is_ok()
+        } else {
+            let renamed = std::fs::rename(&current_path, &rotated_path).is_ok();
+            if renamed && self.config.gzip {
+                let _ = gzip_file(&rotated_path, &self.config.rotated_path(1, true));
+            }
+            renamed
+        };
+
+        if !moved {
+            return;
+        }
+
+        // Note: If no new file can be opened, events are still written using the previous file handle.
+        if let Ok(file) = open_append(&current_path) {
+            self.file = file;
+            self.file_size = 0;
+        }
+    }
+
+    /// Moves every rotated file one position back, and deletes files exceeding the maximum number of rotated files.
+    ///
+    /// **Note:** Nothing is moved if the first rotated position is free (e.g. after a failed rotation),
+    /// so retried rotations do not delete rotated files.
+    fn shift_rotated_files(&self) -> std::io::Result<()> {
+        let max_rotated_files = self.config.max_rotated_files;
+        if !self.config.rotated_path(1, false).exists()
+            && !self.config.rotated_path(1, true).exists()
+        {
+            return Ok(());
+        }
+
+        for gzip in [false, true] {
+            let _ = std::fs::remove_file(self.config.rotated_path(max_rotated_files, gzip));
+        }
+
+        for position in (1..max_rotated_files).rev() {
+            for gzip in [false, true] {
+                let from = self.config.rotated_path(position, gzip);
+                if from.exists() {
+                    std::fs::rename(&from, self.config.rotated_path(position + 1, gzip))?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn open_append(path: &Path) -> std::io::Result<File> {
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+/// Compresses the file at `from` into the file at `to`, and removes the file at `from`.
+fn gzip_file(from: &Path, to: &Path) -> std::io::Result<()> {
+    let mut encoder =
+        flate2::write::GzEncoder::new(File::create(to)?, flate2::Compression::default());
+    std::io::copy(&mut File::open(from)?, &mut encoder)?;
+    encoder.finish()?.flush()?;
+    std::fs::remove_file(from)
+}
+
+fn add_dropped(dropped: &AtomicUsize, nr_events: usize) {
+    let _ = dropped.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |dropped| {
+        dropped.checked_add(nr_events)
+    });
+}
@@ -1,19 +1,23 @@
-//! Contains the [`Subscription`] struct that is returned by `subscribe` functions from an [`EvidentPublisher`].
+//! Contains the [`Subscription`] and [`OwnedSubscription`] structs that are returned by `subscribe` functions from an [`EvidentPublisher`].
 //!
 //! [req:subs]
 
 use std::{
     collections::{HashMap, HashSet},
     hash::Hash,
+    ops::Deref,
     sync::{
-        mpsc::{Receiver, SyncSender},
-        Arc,
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        mpsc::TrySendError,
+        Arc, RwLock,
     },
+    time::Duration,
 };
 
 use crate::{
+    channel::{Receiver, Sender},
     event::{entry::EventEntry, filter::Filter, Event, Id, Msg},
-    publisher::{CaptureControl, EvidentPublisher},
+    publisher::{CaptureControl, EvidentPublisher, IdSubscriber},
 };
 
 /// Subscription that is returned when subscribing to events captured by an [`EvidentPublisher`].
@@ -26,22 +30,11 @@ where
     T: EventEntry<K, M>,
     F: Filter<K, M>,
 {
-    /// The ID of the channel used to send events from the [`EvidentPublisher`] to the [`Subscription`].
-    pub(crate) channel_id: crate::uuid::Uuid,
+    /// The connection to the [`EvidentPublisher`] the [`Subscription`] was created from.
+    pub(crate) core: SubscriptionCore<'p, K, M, T, F>,
 
     /// The channel [`Receiver`] used to receive captured events from the [`EvidentPublisher`].
     pub(crate) receiver: Receiver<Arc<Event<K, M, T>>>,
-
-    /// Flag set to `true` if this [`Subscription`] is subscribed to receive all captured events.
-    pub(crate) sub_to_all: bool,
-
-    /// Optional set of event-IDs this [`Subscription`] is subscribed to.
-    ///
-    /// **Note:** Only relevant for subscriptions to specific event-IDs.
-    pub(crate) subscriptions: Option<HashSet<K>>,
-
-    /// A reference to the [`EvidentPublisher`] the [`Subscription`] was created from.
-    pub(crate) publisher: &'p EvidentPublisher<K, M, T, F>,
 }
 
 impl<'p, K, M, T, F> Subscription<'p, K, M, T, F>
@@ -52,6 +45,8 @@ where
     F: Filter<K, M>,
 {
     /// Get the [`Receiver`] of the subscription channel.
+    ///
+    /// **Note:** The receiver is a [`channel::Receiver`](crate::channel::Receiver), and no [`std::sync::mpsc::Receiver`].
     pub fn get_receiver(&self) -> &Receiver<Arc<Event<K, M, T>>> {
         &self.receiver
     }
@@ -61,6 +56,24 @@ where
         drop(self)
     }
 
+    /// Returns the ID of the subscription channel.
+    ///
+    /// **Note:** May be used to identify this subscription in [`LaggingSubscription`]s.
+    pub fn get_channel_id(&self) -> crate::uuid::Uuid {
+        self.core.channel_id
+    }
+
+    /// Returns the number of events that could not be sent to this subscription since last reset,
+    /// because the subscription channel was full.
+    pub fn get_missed_events(&self) -> usize {
+        self.core.missed_events.load(Ordering::Relaxed)
+    }
+
+    /// Resets the number of missed events of this subscription.
+    pub fn reset_missed_events(&self) {
+        self.core.missed_events.store(0, Ordering::Relaxed);
+    }
+
     /// Unsubscribes from the given event-ID.
     ///
     /// **Note:** Only possible for subscriptions to specific IDs.
@@ -75,7 +88,7 @@ where
     /// * [`SubscriptionError::UnsubscribeWouldDeleteSubscription`] ... If the [`Subscription`] would not be subscribed to any ID afterwards
     /// * [`SubscriptionError::AllEventsSubscriptionNotModifiable`] ... If the [`Subscription`] was created to receive all events
     pub fn unsubscribe_id(&mut self, id: K) -> Result<(), SubscriptionError<K>> {
-        self.unsubscribe_many(vec![id])
+        self.core.unsubscribe_many(vec![id])
     }
 
     /// Unsubscribes from the given list of event-IDs.
@@ -92,35 +105,7 @@ where
     /// * [`SubscriptionError::UnsubscribeWouldDeleteSubscription`] ... If the [`Subscription`] would not be subscribed to any ID afterwards
     /// * [`SubscriptionError::AllEventsSubscriptionNotModifiable`] ... If the [`Subscription`] was created to receive all events
     pub fn unsubscribe_many(&mut self, ids: Vec<K>) -> Result<(), SubscriptionError<K>> {
-        if self.sub_to_all || self.subscriptions.is_none() {
-            return Err(SubscriptionError::AllEventsSubscriptionNotModifiable);
-        }
-
-        let subs = self.subscriptions.as_mut().unwrap();
-
-        if ids.len() >= subs.len() {
-            return Err(SubscriptionError::UnsubscribeWouldDeleteSubscription);
-        }
-
-        for id in &ids {
-            if !subs.contains(id) {
-                return Err(SubscriptionError::IdNotSubscribed(id.clone()));
-            }
-        }
-
-        match self.publisher.subscriptions.write() {
-            Ok(mut publisher_subs) => {
-                for id in ids {
-                    if let Some(id_sub) = publisher_subs.get_mut(&id) {
-                        let _ = id_sub.remove(&self.channel_id);
-                    }
-                    subs.remove(&id);
-                }
-
-                Ok(())
-            }
-            Err(_) => Err(SubscriptionError::CouldNotAccessPublisher),
-        }
+        self.core.unsubscribe_many(ids)
     }
 
     /// Subscribes to the given event-ID.
@@ -140,7 +125,7 @@ where
     ///
     /// [req:subs.specific.one]
     pub fn subscribe_id(&mut self, id: K) -> Result<(), SubscriptionError<K>> {
-        self.subscribe_many(vec![id])
+        self.core.subscribe_many(vec![id])
     }
 
     /// Subscribes to the given list of event-IDs.
@@ -160,6 +145,225 @@ where
     ///
     /// [req:subs.specific.mult]
     pub fn subscribe_many(&mut self, ids: Vec<K>) -> Result<(), SubscriptionError<K>> {
+        self.core.subscribe_many(ids)
+    }
+}
+
+/// Subscription that is returned when subscribing to events captured by an [`EvidentPublisher`] that is shared using an [`Arc`].
+///
+/// In contrast to a [`Subscription`], it holds an [`Arc`] instead of a reference to the [`EvidentPublisher`],
+/// so it may be moved to other threads, or stored in long-lived structs, even if the publisher is not `static`.
+///
+/// [req:subs]
+pub struct OwnedSubscription<K, M, T, F>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+    T: EventEntry<K, M>,
+    F: Filter<K, M> + 'static,
+{
+    /// The connection to the [`EvidentPublisher`] the [`OwnedSubscription`] was created from.
+    pub(crate) core: SubscriptionCore<'static, K, M, T, F>,
+
+    /// The channel [`Receiver`] used to receive captured events from the [`EvidentPublisher`].
+    pub(crate) receiver: Receiver<Arc<Event<K, M, T>>>,
+}
+
+impl<K, M, T, F> OwnedSubscription<K, M, T, F>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+    T: EventEntry<K, M>,
+    F: Filter<K, M> + 'static,
+{
+    /// Get the [`Receiver`] of the subscription channel.
+    ///
+    /// **Note:** The receiver is a [`channel::Receiver`](crate::channel::Receiver), and no [`std::sync::mpsc::Receiver`].
+    pub fn get_receiver(&self) -> &Receiver<Arc<Event<K, M, T>>> {
+        &self.receiver
+    }
+
+    /// Unsubscribes this subscription.
+    pub fn unsubscribe(self) {
+        drop(self)
+    }
+
+    /// Returns the ID of the subscription channel.
+    ///
+    /// **Note:** May be used to identify this subscription in [`LaggingSubscription`]s.
+    pub fn get_channel_id(&self) -> crate::uuid::Uuid {
+        self.core.channel_id
+    }
+
+    /// Returns the number of events that could not be sent to this subscription since last reset,
+    /// because the subscription channel was full.
+    pub fn get_missed_events(&self) -> usize {
+        self.core.missed_events.load(Ordering::Relaxed)
+    }
+
+    /// Resets the number of missed events of this subscription.
+    pub fn reset_missed_events(&self) {
+        self.core.missed_events.store(0, Ordering::Relaxed);
+    }
+
+    /// Unsubscribes from the given event-ID.
+    ///
+    /// See [`Subscription::unsubscribe_id`] for possible errors.
+    pub fn unsubscribe_id(&mut self, id: K) -> Result<(), SubscriptionError<K>> {
+        self.core.unsubscribe_many(vec![id])
+    }
+
+    /// Unsubscribes from the given list of event-IDs.
+    ///
+    /// See [`Subscription::unsubscribe_many`] for possible errors.
+    pub fn unsubscribe_many(&mut self, ids: Vec<K>) -> Result<(), SubscriptionError<K>> {
+        self.core.unsubscribe_many(ids)
+    }
+
+    /// Subscribes to the given event-ID.
+    ///
+    /// See [`Subscription::subscribe_id`] for possible errors.
+    ///
+    /// [req:subs.specific.one]
+    pub fn subscribe_id(&mut self, id: K) -> Result<(), SubscriptionError<K>> {
+        self.core.subscribe_many(vec![id])
+    }
+
+    /// Subscribes to the given list of event-IDs.
+    ///
+    /// See [`Subscription::subscribe_many`] for possible errors.
+    ///
+    /// [req:subs.specific.mult]
+    pub fn subscribe_many(&mut self, ids: Vec<K>) -> Result<(), SubscriptionError<K>> {
+        self.core.subscribe_many(ids)
+    }
+}
+
+/// Connection between a subscription and the [`EvidentPublisher`] it was created from.
+///
+/// It is shared by all subscription types to (un)subscribe event-IDs,
+/// and removes the subscription from the [`EvidentPublisher`] on `drop()`.
+///
+/// [req:subs]
+pub(crate) struct SubscriptionCore<'p, K, M, T, F>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+    T: EventEntry<K, M>,
+    F: Filter<K, M>,
+{
+    /// The ID of the channel used to send events from the [`EvidentPublisher`] to the subscription.
+    pub(crate) channel_id: crate::uuid::Uuid,
+
+    /// Flag set to `true` if the subscription is subscribed to receive all captured events.
+    pub(crate) sub_to_all: bool,
+
+    /// Flag set to `true` if the subscription also receives events of descendants of the subscribed event-IDs.
+    ///
+    /// **Note:** Only relevant for subscriptions to specific event-IDs.
+    pub(crate) prefix: bool,
+
+    /// Optional set of event-IDs the subscription is subscribed to.
+    ///
+    /// **Note:** Only relevant for subscriptions to specific event-IDs.
+    pub(crate) subscriptions: Option<HashSet<K>>,
+
+    /// The [`EvidentPublisher`] the subscription was created from.
+    pub(crate) publisher: PublisherRef<'p, K, M, T, F>,
+
+    /// Number of events that could not be sent to the subscription, because the subscription channel was full.
+    pub(crate) missed_events: Arc<AtomicUsize>,
+}
+
+/// Either a reference to, or a shared [`EvidentPublisher`].
+///
+/// [req:subs]
+pub(crate) enum PublisherRef<'p, K, M, T, F>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+    T: EventEntry<K, M>,
+    F: Filter<K, M>,
+{
+    /// Reference to the [`EvidentPublisher`] used by [`Subscription`]s.
+    Borrowed(&'p EvidentPublisher<K, M, T, F>),
+
+    /// Shared [`EvidentPublisher`] used by [`OwnedSubscription`]s.
+    Owned(Arc<EvidentPublisher<K, M, T, F>>),
+}
+
+impl<'p, K, M, T, F> Deref for PublisherRef<'p, K, M, T, F>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+    T: EventEntry<K, M>,
+    F: Filter<K, M>,
+{
+    type Target = EvidentPublisher<K, M, T, F>;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            PublisherRef::Borrowed(publisher) => publisher,
+            PublisherRef::Owned(publisher) => publisher,
+        }
+    }
+}
+
+impl<'p, K, M, T, F> SubscriptionCore<'p, K, M, T, F>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+    T: EventEntry<K, M>,
+    F: Filter<K, M>,
+{
+    /// Returns the hashmap of the given [`EvidentPublisher`] that stores subscriptions to specific event-IDs,
+    /// or to specific event-IDs and their descendants if `prefix` is `true`.
+    fn id_subscriptions(
+        publisher: &EvidentPublisher<K, M, T, F>,
+        prefix: bool,
+    ) -> &RwLock<IdSubscriber<K, M, T>> {
+        if prefix {
+            &publisher.prefix_subscriptions
+        } else {
+            &publisher.subscriptions
+        }
+    }
+
+    /// Unsubscribes from the given list of event-IDs.
+    pub(crate) fn unsubscribe_many(&mut self, ids: Vec<K>) -> Result<(), SubscriptionError<K>> {
+        if self.sub_to_all || self.subscriptions.is_none() {
+            return Err(SubscriptionError::AllEventsSubscriptionNotModifiable);
+        }
+
+        let subs = self.subscriptions.as_mut().unwrap();
+
+        if ids.len() >= subs.len() {
+            return Err(SubscriptionError::UnsubscribeWouldDeleteSubscription);
+        }
+
+        for id in &ids {
+            if !subs.contains(id) {
+                return Err(SubscriptionError::IdNotSubscribed(id.clone()));
+            }
+        }
+
+        match Self::id_subscriptions(&self.publisher, self.prefix).write() {
+            Ok(mut publisher_subs) => {
+                for id in ids {
+                    if let Some(id_sub) = publisher_subs.get_mut(&id) {
+                        let _ = id_sub.remove(&self.channel_id);
+                    }
+                    subs.remove(&id);
+                }
+
+                Ok(())
+            }
+            Err(_) => Err(SubscriptionError::CouldNotAccessPublisher),
+        }
+    }
+
+    /// Subscribes to the given list of event-IDs.
+    pub(crate) fn subscribe_many(&mut self, ids: Vec<K>) -> Result<(), SubscriptionError<K>> {
         if self.sub_to_all || self.subscriptions.is_none() {
             return Err(SubscriptionError::AllEventsSubscriptionNotModifiable);
         }
@@ -179,7 +383,7 @@ where
             }
         };
 
-        let sender = match self.publisher.subscriptions.read() {
+        let sender = match Self::id_subscriptions(&self.publisher, self.prefix).read() {
             Ok(publisher_subs) => match publisher_subs.get(any_sub_id) {
                 Some(id_subs) => match id_subs.get(&self.channel_id) {
                     Some(sub_sender) => sub_sender.clone(),
@@ -196,7 +400,7 @@ where
             }
         };
 
-        match self.publisher.subscriptions.write() {
+        match Self::id_subscriptions(&self.publisher, self.prefix).write() {
             Ok(mut publisher_subs) => {
                 for id in ids {
                     publisher_subs
@@ -220,7 +424,7 @@ where
     }
 }
 
-impl<'p, K, M, T, F> Drop for Subscription<'p, K, M, T, F>
+impl<'p, K, M, T, F> Drop for SubscriptionCore<'p, K, M, T, F>
 where
     K: Id + CaptureControl,
     M: Msg,
@@ -234,7 +438,9 @@ where
                 let _ = locked_any_event.remove(&self.channel_id);
             }
         } else if let Some(self_subs) = &self.subscriptions {
-            if let Ok(mut publisher_subs) = self.publisher.subscriptions.try_write() {
+            if let Ok(mut publisher_subs) =
+                Self::id_subscriptions(&self.publisher, self.prefix).try_write()
+            {
                 for k in self_subs {
                     if let Some(id_sub) = publisher_subs.get_mut(k) {
                         let _ = id_sub.remove(&self.channel_id);
@@ -253,7 +459,7 @@ where
     F: Filter<K, M>,
 {
     fn eq(&self, other: &Self) -> bool {
-        self.channel_id == other.channel_id
+        self.core.channel_id == other.core.channel_id
     }
 }
 
@@ -274,7 +480,40 @@ where
     F: Filter<K, M>,
 {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        self.channel_id.hash(state);
+        self.core.channel_id.hash(state);
+    }
+}
+
+impl<K, M, T, F> PartialEq for OwnedSubscription<K, M, T, F>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+    T: EventEntry<K, M>,
+    F: Filter<K, M> + 'static,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.core.channel_id == other.core.channel_id
+    }
+}
+
+impl<K, M, T, F> Eq for OwnedSubscription<K, M, T, F>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+    T: EventEntry<K, M>,
+    F: Filter<K, M> + 'static,
+{
+}
+
+impl<K, M, T, F> Hash for OwnedSubscription<K, M, T, F>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+    T: EventEntry<K, M>,
+    F: Filter<K, M> + 'static,
+{
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.core.channel_id.hash(state);
     }
 }
 
@@ -305,6 +544,52 @@ pub enum SubscriptionError<K: Id> {
 
     /// No *sender-part* of the subscription-channel between this [`Subscription`] and the [`EvidentPublisher`] is available in the [`EvidentPublisher`].
     NoSubscriptionChannelAvailable,
+
+    /// The [`EvidentPublisher`] was shut down, and no longer accepts new subscriptions.
+    PublisherShutDown,
+}
+
+/// Defines what happens if an [`EvidentPublisher`] tries to send an event to a subscription with a full channel.
+///
+/// Events that are dropped are counted as *missed events* of the subscription.
+///
+/// [req:subs]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Blocks in [`CaptureMode::Blocking`](crate::publisher::CaptureMode::Blocking),
+    /// and drops the new event in [`CaptureMode::NonBlocking`](crate::publisher::CaptureMode::NonBlocking).
+    #[default]
+    FollowCaptureMode,
+
+    /// Blocks until the subscription has space for the new event.
+    ///
+    /// **Note:** Blocking delays forwarding events to all other subscriptions.
+    Block,
+
+    /// Drops the new event.
+    DropNewest,
+
+    /// Drops the oldest buffered event to make space for the new event.
+    DropOldest,
+
+    /// Blocks until the subscription has space for the new event, or drops the new event once the timeout is reached.
+    ///
+    /// **Note:** Blocking delays forwarding events to all other subscriptions.
+    BlockTimeout(Duration),
+}
+
+/// Information about a subscription that missed events, because its channel was full.
+///
+/// [req:subs]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LaggingSubscription {
+    /// The ID of the subscription channel.
+    ///
+    /// **Note:** Use `get_channel_id()` of a subscription to identify it.
+    pub channel_id: crate::uuid::Uuid,
+
+    /// Number of events the subscription missed.
+    pub missed_events: usize,
 }
 
 /// *Sender-part* of the subscription-channel between a [`Subscription`] and an [`EvidentPublisher`].
@@ -320,10 +605,229 @@ where
     /// ID to identify the *sender-part* in the [`EvidentPublisher`].
     pub(crate) channel_id: crate::uuid::Uuid,
 
-    /// [`SyncSender`] of the [`sync_channel`](std::sync::mpsc::sync_channel) between [`Subscription`] and [`EvidentPublisher`].
-    pub(crate) sender: SyncSender<Arc<Event<K, M, T>>>,
+    /// *Sender-part* of the channel between subscription and [`EvidentPublisher`].
+    pub(crate) sender: EventSender<K, M, T>,
+
+    /// Optional predicate an event must fulfill to be sent to the subscription.
+    pub(crate) predicate: Option<EventPredicate<K, M, T>>,
+
+    /// Defines what happens if the subscription channel is full.
+    pub(crate) overflow_policy: OverflowPolicy,
+
+    /// Number of events that could not be sent to the subscription, because the subscription channel was full.
+    pub(crate) missed_events: Arc<AtomicUsize>,
+
+    /// Sequence number of the first event that is forwarded to the subscription.
+    /// Earlier events were either sent as retained events, or captured before the subscription was added.
+    pub(crate) start_seq: Arc<AtomicU64>,
 }
 
+impl<K, M, T> SubscriptionSender<K, M, T>
+where
+    K: Id,
+    M: Msg,
+    T: EventEntry<K, M>,
+{
+    /// Creates a new [`SubscriptionSender`] with a new channel ID,
+    /// that follows the capture mode of the [`EvidentPublisher`] if the subscription channel is full.
+    pub(crate) fn new(
+        sender: EventSender<K, M, T>,
+        predicate: Option<EventPredicate<K, M, T>>,
+    ) -> Self {
+        Self::with_policy(
+            sender,
+            predicate,
+            OverflowPolicy::FollowCaptureMode,
+            Arc::new(AtomicUsize::new(0)),
+        )
+    }
+
+    /// Creates a new [`SubscriptionSender`] with a new channel ID, and the given [`OverflowPolicy`].
+    pub(crate) fn with_policy(
+        sender: EventSender<K, M, T>,
+        predicate: Option<EventPredicate<K, M, T>>,
+        overflow_policy: OverflowPolicy,
+        missed_events: Arc<AtomicUsize>,
+    ) -> Self {
+        SubscriptionSender {
+            channel_id: crate::uuid::Uuid::new_v4(),
+            sender,
+            predicate,
+            overflow_policy,
+            missed_events,
+            start_seq: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Forwards the event to the subscription if the event fulfills the predicate of the subscription.
+    /// The [`OverflowPolicy`] of the subscription defines what happens if the subscription channel is full.
+    ///
+    /// Returns `false` if the subscription channel is disconnected.
+    pub(crate) fn forward(&self, event: Arc<Event<K, M, T>>, capture_blocking: bool) -> bool {
+        if !self.allows(&event) {
+            return true;
+        }
+
+        let res = match self.overflow_policy {
+            OverflowPolicy::Block => {
+                return self.send(event);
+            }
+            OverflowPolicy::FollowCaptureMode if capture_blocking => {
+                return self.send(event);
+            }
+            OverflowPolicy::BlockTimeout(timeout) => self.send_timeout(event, timeout),
+            OverflowPolicy::DropOldest => self.force_send(event),
+            OverflowPolicy::FollowCaptureMode | OverflowPolicy::DropNewest => self.try_send(event),
+        };
+
+        match res {
+            Ok(()) => true,
+            Err(TrySendError::Full(_)) => {
+                let _ = self.missed_events.fetch_update(
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                    |missed| missed.checked_add(1),
+                );
+                true
+            }
+            Err(TrySendError::Disconnected(_)) => false,
+        }
+    }
+
+    /// Sends a retained event to a new subscription if the event fulfills the predicate of the subscription.
+    ///
+    /// **Note:** Retained events do not count against the bound of a sync subscription channel,
+    /// so no retained event is missed, and the current thread is never blocked.
+    pub(crate) fn send_initial(&self, event: Arc<Event<K, M, T>>) {
+        if !self.allows(&event) {
+            return;
+        }
+
+        match &self.sender {
+            EventSender::Sync(sender) => {
+                let _ = sender.send_unbounded(event);
+            }
+            #[cfg(feature = "async")]
+            EventSender::Async(_) => {
+                self.forward(event, false);
+            }
+        }
+    }
+
+    /// Returns `true` if the event fulfills the predicate of the subscription, or no predicate is set.
+    pub(crate) fn allows(&self, event: &Event<K, M, T>) -> bool {
+        match &self.predicate {
+            Some(predicate) => predicate(event),
+            None => true,
+        }
+    }
+
+    /// Sends the event to the subscription, blocking the current thread if the channel is full.
+    ///
+    /// Returns `false` if the subscription channel is disconnected.
+    pub(crate) fn send(&self, event: Arc<Event<K, M, T>>) -> bool {
+        match &self.sender {
+            EventSender::Sync(sender) => sender.send(event).is_ok(),
+            #[cfg(feature = "async")]
+            EventSender::Async(sender) => match sender.lock() {
+                Ok(mut locked_sender) => {
+                    futures::executor::block_on(futures::SinkExt::send(&mut *locked_sender, event))
+                        .is_ok()
+                }
+                Err(_) => false,
+            },
+        }
+    }
+
+    /// Tries to send the event to the subscription without blocking the current thread.
+    pub(crate) fn try_send(&self, event: Arc<Event<K, M, T>>) -> Result<(), TrySendError<()>> {
+        match &self.sender {
+            EventSender::Sync(sender) => sender.try_send(event).map_err(|err| match err {
+                TrySendError::Full(_) => TrySendError::Full(()),
+                TrySendError::Disconnected(_) => TrySendError::Disconnected(()),
+            }),
+            #[cfg(feature = "async")]
+            EventSender::Async(sender) => match sender.lock() {
+                Ok(mut locked_sender) => locked_sender.try_send(event).map_err(|err| {
+                    if err.is_full() {
+                        TrySendError::Full(())
+                    } else {
+                        TrySendError::Disconnected(())
+                    }
+                }),
+                Err(_) => Err(TrySendError::Disconnected(())),
+            },
+        }
+    }
+
+    /// Tries to send the event to the subscription until the timeout is reached.
+    ///
+    /// **Note:** Async subscriptions always follow the capture mode, so they do not wait for the timeout.
+    fn send_timeout(
+        &self,
+        event: Arc<Event<K, M, T>>,
+        timeout: Duration,
+    ) -> Result<(), TrySendError<()>> {
+        match &self.sender {
+            EventSender::Sync(sender) => {
+                sender
+                    .send_timeout(event, timeout)
+                    .map_err(|err| match err {
+                        TrySendError::Full(_) => TrySendError::Full(()),
+                        TrySendError::Disconnected(_) => TrySendError::Disconnected(()),
+                    })
+            }
+            #[cfg(feature = "async")]
+            EventSender::Async(_) => self.try_send(event),
+        }
+    }
+
+    /// Sends the event to the subscription without blocking the current thread,
+    /// and drops the oldest buffered event if the channel is full.
+    ///
+    /// **Note:** Async subscriptions always follow the capture mode, so they never drop the oldest event.
+    fn force_send(&self, event: Arc<Event<K, M, T>>) -> Result<(), TrySendError<()>> {
+        match &self.sender {
+            EventSender::Sync(sender) => match sender.force_send(event) {
+                // Note: The dropped oldest event is counted as missed, so the result mirrors a full channel.
+                Ok(true) => Err(TrySendError::Full(())),
+                Ok(false) => Ok(()),
+                Err(_) => Err(TrySendError::Disconnected(())),
+            },
+            #[cfg(feature = "async")]
+            EventSender::Async(_) => self.try_send(event),
+        }
+    }
+}
+
+/// The *sender-part* of the different channel types used between subscriptions and an [`EvidentPublisher`].
+///
+/// [req:subs]
+#[derive(Clone)]
+pub(crate) enum EventSender<K, M, T>
+where
+    K: Id,
+    M: Msg,
+    T: EventEntry<K, M>,
+{
+    /// Sender of the [`channel`](crate::channel) between [`Subscription`] and [`EvidentPublisher`].
+    Sync(Sender<Arc<Event<K, M, T>>>),
+
+    /// Sender of the [`channel`](futures::channel::mpsc::channel) between [`AsyncSubscription`](crate::async_subscription::AsyncSubscription) and [`EvidentPublisher`].
+    ///
+    /// **Note:** The sender is shared, because sending requires mutable access.
+    #[cfg(feature = "async")]
+    Async(AsyncSender<K, M, T>),
+}
+
+// Types below used for better clarity according to clippy.
+
+pub(crate) type EventPredicate<K, M, T> = Arc<dyn Fn(&Event<K, M, T>) -> bool + Send + Sync>;
+
+#[cfg(feature = "async")]
+type AsyncSender<K, M, T> =
+    Arc<std::sync::Mutex<futures::channel::mpsc::Sender<Arc<Event<K, M, T>>>>>;
+
 impl<K, M, T> PartialEq for SubscriptionSender<K, M, T>
 where
     K: Id,
@@ -0,0 +1,295 @@
+//! Contains the [`EvidentLayer`] that captures [`tracing`] events as *evident* events,
+//! and the [`TracingForwarder`] that emits *evident* events as [`tracing`] events.
+//!
+//! **Note:** Forwarded events use the target [`FORWARDED_TARGET`], and are ignored by [`EvidentLayer`]s to prevent loops.
+//!
+//! [req:cap], [req:subs]
+
+use std::{
+    convert::Infallible,
+    sync::{atomic::AtomicBool, Arc},
+};
+
+use tracing::{field::Field, Level, Metadata, Subscriber};
+use tracing_subscriber::{layer::Context, Layer};
+
+use crate::{
+    bridged_event::BridgedEvent,
+    channel::Receiver,
+    event::{
+        entry::EventEntry, filter::Filter, intermediary::IntermediaryEvent, origin::Origin, Event,
+        Id, Msg,
+    },
+    publisher::{CaptureControl, EvidentPublisher},
+    subscription::{OwnedSubscription, PublisherRef, Subscription},
+    worker::{consume_events, EventWorker},
+};
+
+/// Target of [`tracing`] events emitted for *evident* events.
+pub const FORWARDED_TARGET: &str = "evident";
+
+/// Function to map the metadata and fields of a [`tracing`] event to an event-ID.
+///
+/// Tracing events are ignored if `None` is returned.
+pub type TracingMapping<K> = Box<dyn Fn(&Metadata<'_>, &TracingFields) -> Option<K> + Send + Sync>;
+
+/// Function to map an event-ID to the [`Level`] of the emitted [`tracing`] event.
+pub type LevelMapping<K> = Box<dyn Fn(&K) -> Level + Send>;
+
+/// Fields recorded for a [`tracing`] event.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TracingFields {
+    message: Option<String>,
+    fields: Vec<(&'static str, String)>,
+}
+
+impl TracingFields {
+    /// Returns the message of the tracing event, or `None` if no message was set.
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    /// Returns the value of the field with the given name, or `None` if the field was not set.
+    ///
+    /// **Note:** String values are returned as is. All other values are formatted using `Debug`.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(field_name, _)| *field_name == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns an iterator over the names and values of all fields except the message.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &str)> {
+        self.fields
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+    }
+}
+
+impl tracing::field::Visit for TracingFields {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = Some(value.to_string());
+        } else {
+            self.fields.push((field.name(), value.to_string()));
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.message = Some(format!("{:?}", value));
+        } else {
+            self.fields.push((field.name(), format!("{:?}", value)));
+        }
+    }
+}
+
+/// [`Layer`] that converts [`tracing`] events into *evident* events, and captures them with an [`EvidentPublisher`].
+///
+/// The message of a tracing event is used as event message,
+/// and the module path, file, and line of the tracing event are used for the event [`Origin`].
+///
+/// Events are captured like events set with `set_event!()`,
+/// so the filter, capture control, and missed-capture accounting of the publisher apply.
+///
+/// **Note:** Tracing events inside the event handler of the publisher might block forever in *blocking* capture mode,
+/// because the handler would wait on itself if the capture buffer is full.
+pub struct EvidentLayer<K, M, T, F>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+    T: EventEntry<K, M>,
+    F: Filter<K, M> + 'static,
+{
+    publisher: PublisherRef<'static, K, M, T, F>,
+    mapping: TracingMapping<K>,
+}
+
+impl<K, M, T, F> EvidentLayer<K, M, T, F>
+where
+    K: Id + CaptureControl,
+    M: Msg,
+    T: EventEntry<K, M>,
+    F: Filter<K, M> + Send + Sync + 'static,
+    String: Into<M>,
+{
+    /// Creates a new [`EvidentLayer`] for the given static publisher.
+    ///
+    /// # Arguments
+    ///
+    /// * `publisher` ... The publisher used to capture tracing events
+    /// * `mapping` ... Function to map the metadata and fields of a tracing event to an event-ID, or `None` to ignore the tracing event
+    pub fn new(
+        publisher: &'static EvidentPublisher<K, M, T, F>,
+        mapping: impl Fn(&Metadata<'_>, &TracingFields) -> Option<K> + Send + Sync + 'static,
+    ) -> Self {
+        EvidentLayer {
+            publisher: PublisherRef::Borrowed(publisher),
+            mapping: Box::new(mapping),
+        }
+    }
+
+    /// Creates a new [`EvidentLayer`] for the given shared publisher.
+    ///
+    /// See [`new`](Self::new) for more information.
+    pub fn new_shared(
+        publisher: Arc<EvidentPublisher<K, M, T, F>>,
+        mapping: impl Fn(&Metadata<'_>, &TracingFields) -> Option<K> + Send + Sync + 'static,
+    ) -> Self {
+        EvidentLayer {
+            publisher: PublisherRef::Owned(publisher),
+            mapping: Box::new(mapping),
+        }
+    }
+}
+
+impl<S, K, M, T, F> Layer<S> for EvidentLayer<K, M, T, F>
+where
+    S: Subscriber,
+    K: Id + CaptureControl,
+    M: Msg,
+    T: EventEntry<K, M>,
+    F: Filter<K, M> + Send + Sync + 'static,
+    String: Into<M>,
+{
+    fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        if metadata.target() == FORWARDED_TARGET {
+            return;
+        }
+
+        let mut fields = TracingFields::default();
+        event.record(&mut fields);
+
+        let Some(event_id) = (self.mapping)(metadata, &fields) else {
+            return;
+        };
+
+        let origin = Origin::new(
+            metadata.module_path().unwrap_or_default(),
+            metadata.file().unwrap_or_default(),
+            metadata.line().unwrap_or_default(),
+        );
+
+        let mut tracing_event: BridgedEvent<K, M, T> =
+            BridgedEvent::new(event_id, fields.message, origin);
+        self.publisher._capture(&mut tracing_event);
+    }
+}
+
+/// Emits the given *evident* event as [`tracing`] event with the given level.
+///
+/// The tracing event uses the target [`FORWARDED_TARGET`], and has the fields
+/// `event_id`, `entry_id`, `origin`, and `msg`.
+pub fn emit_tracing_event<K, M, T>(event: &Event<K, M, T>, level: Level)
+where
+    K: Id,
+    M: Msg,
+    T: EventEntry<K, M>,
+{
+    // Note: The level of tracing events must be known at compile time.
+    macro_rules! emit {
+        ($level:expr) => {
+            tracing::event!(
+                target: FORWARDED_TARGET,
+                $level,
+                event_id = ?event.get_event_id(),
+                entry_id = %event.get_entry_id(),
+                origin = %event.get_origin(),
+                msg = ?event.get_msg(),
+            )
+        };
+    }
+
+    match level {
+        Level::ERROR => emit!(Level::ERROR),
+        Level::WARN => emit!(Level::WARN),
+        Level::INFO => emit!(Level::INFO),
+        Level::DEBUG => emit!(Level::DEBUG),
+        Level::TRACE => emit!(Level::TRACE),
+    }
+}
+
+/// Consumer that emits every event received by a subscription as [`tracing`] event.
+///
+/// Events are emitted by a separate thread that takes ownership of the subscription.
+/// The forwarding thread stops once [`stop`](Self::stop) is called, the forwarder is dropped, or the publisher is shut down.
+///
+/// **Note:** Tracing events are emitted to the global default subscriber, because they are emitted on a separate thread.
+pub struct TracingForwarder {
+    worker: EventWorker<()>,
+}
+
+impl TracingForwarder {
+    /// Emits all events received by the given subscription of a static publisher as tracing events,
+    /// using the given function to map event-IDs to tracing levels.
+    pub fn spawn<K, M, T, F>(
+        subscription: Subscription<'static, K, M, T, F>,
+        level: impl Fn(&K) -> Level + Send + 'static,
+    ) -> Self
+    where
+        K: Id + CaptureControl,
+        M: Msg,
+        T: EventEntry<K, M>,
+        F: Filter<K, M> + Send + Sync,
+    {
+        let level: LevelMapping<K> = Box::new(level);
+
+        TracingForwarder {
+            worker: EventWorker::spawn(move |stop: &AtomicBool| {
+                forward_events(subscription.get_receiver(), level, stop)
+            }),
+        }
+    }
+
+    /// Emits all events received by the given owned subscription as tracing events,
+    /// using the given function to map event-IDs to tracing levels.
+    pub fn spawn_owned<K, M, T, F>(
+        subscription: OwnedSubscription<K, M, T, F>,
+        level: impl Fn(&K) -> Level + Send + 'static,
+    ) -> Self
+    where
+        K: Id + CaptureControl,
+        M: Msg,
+        T: EventEntry<K, M>,
+        F: Filter<K, M> + Send + Sync + 'static,
+    {
+        let level: LevelMapping<K> = Box::new(level);
+
+        TracingForwarder {
+            worker: EventWorker::spawn(move |stop: &AtomicBool| {
+                forward_events(subscription.get_receiver(), level, stop)
+            }),
+        }
+    }
+
+    /// Stops the forwarding thread once all events in the subscription channel are emitted.
+    ///
+    /// **Note:** Use `flush()` of the publisher beforehand to make sure all captured events reached the subscription channel.
+    pub fn stop(self) {
+        let _ = self.worker.stop();
+    }
+}
+
+/// Emits events of the given receiver as tracing events, until `stop` is set or the channel is disconnected.
+fn forward_events<K, M, T>(
+    receiver: &Receiver<Arc<Event<K, M, T>>>,
+    level: LevelMapping<K>,
+    stop: &AtomicBool,
+) where
+    K: Id,
+    M: Msg,
+    T: EventEntry<K, M>,
+{
+    let _ = consume_events::<_, _, Infallible>(
+        receiver,
+        stop,
+        &mut (),
+        |_, event| {
+            emit_tracing_event(&event, level(event.get_event_id()));
+            Ok(())
+        },
+        |_| Ok(()),
+    );
+}
@@ -0,0 +1,100 @@
+//! Contains the [`EventWorker`] that consumes events of a subscription channel in a separate thread.
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{RecvTimeoutError, TryRecvError},
+        Arc,
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use crate::channel::Receiver;
+
+/// Interval in which an idle [`EventWorker`] checks if it should stop.
+pub(crate) const STOP_CHECK_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Thread that consumes events until it is stopped, or dropped.
+pub(crate) struct EventWorker<R> {
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<R>>,
+}
+
+impl<R: Send + 'static> EventWorker<R> {
+    /// Spawns a new thread that runs the given function with the stop flag of the worker.
+    pub(crate) fn spawn(work_fn: impl FnOnce(&AtomicBool) -> R + Send + 'static) -> Self {
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = stop.clone();
+        let handle = thread::spawn(move || work_fn(&thread_stop));
+
+        EventWorker {
+            stop,
+            handle: Some(handle),
+        }
+    }
+
+    /// Sets the stop flag, and waits until the thread finished.
+    ///
+    /// Returns the result of the thread, or `None` if the thread panicked.
+    pub(crate) fn stop(mut self) -> Option<R> {
+        self.stop.store(true, Ordering::Release);
+        self.handle.take()?.join().ok()
+    }
+}
+
+impl<R> Drop for EventWorker<R> {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Release);
+
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Passes every event of the given receiver to `on_event`, until `stop` is set or the channel is disconnected.
+/// Events that are still in the channel once `stop` is set are passed to `on_event` before returning.
+///
+/// `on_idle` is called whenever no more events are waiting in the channel,
+/// and at least every [`STOP_CHECK_INTERVAL`] while the channel stays empty.
+///
+/// **Note:** The consumer is passed to both functions, so both may mutate it.
+/// The first error returned by `on_event` or `on_idle` stops consuming events.
+pub(crate) fn consume_events<E, C, Err>(
+    receiver: &Receiver<E>,
+    stop: &AtomicBool,
+    consumer: &mut C,
+    mut on_event: impl FnMut(&mut C, E) -> Result<(), Err>,
+    mut on_idle: impl FnMut(&mut C) -> Result<(), Err>,
+) -> Result<(), Err> {
+    while !stop.load(Ordering::Acquire) {
+        match receiver.try_recv() {
+            Ok(event) => {
+                on_event(consumer, event)?;
+            }
+            Err(TryRecvError::Empty) => {
+                on_idle(consumer)?;
+
+                match receiver.recv_timeout(STOP_CHECK_INTERVAL) {
+                    Ok(event) => {
+                        on_event(consumer, event)?;
+                    }
+                    Err(RecvTimeoutError::Timeout) => {}
+                    Err(RecvTimeoutError::Disconnected) => {
+                        break;
+                    }
+                }
+            }
+            Err(TryRecvError::Disconnected) => {
+                break;
+            }
+        }
+    }
+
+    for event in receiver.try_iter() {
+        on_event(consumer, event)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+//! This module contains a pub/sub-setup with *evident* using the stock entry and intermediary event.
+//!
+//! Only the [`Id`](evident::event::Id) trait must be implemented for this setup.
+//!
+//! [req:qa.ux.usage]
+
+use std::time::Duration;
+
+use evident::event::{entry::EventEntry, intermediary::IntermediaryEvent};
+
+use self::id::DefaultId;
+
+mod id;
+
+evident::create_static_publisher!(PUBLISHER, id_type = DefaultId, msg_type = String);
+
+evident::create_set_event_macro!(no_export, id_type = DefaultId, msg_type = String);
+
+/// Test using the stock pub/sub implementation to set and listen to an event.
+///
+/// [req:cap.test.recv]
+#[test]
+fn setup_default_publisher() {
+    let some_id = DefaultId { id: 3 };
+    let msg = "Some msg";
+
+    let sub = PUBLISHER.subscribe(some_id).unwrap();
+
+    set_event!(some_id, msg).finalize();
+
+    let event = sub
+        .get_receiver()
+        .recv_timeout(Duration::from_millis(100))
+        .unwrap();
+
+    assert_eq!(
+        event.get_event_id(),
+        &some_id,
+        "Sent and received Ids differ."
+    );
+    assert_eq!(
+        event.get_msg().unwrap(),
+        msg,
+        "Sent and received messages differ."
+    );
+}
+
+/// Test that the stock intermediary event is captured once it goes out of scope.
+///
+/// [req:cap.test.recv]
+#[test]
+fn default_event_captured_on_drop() {
+    let some_id = DefaultId { id: 4 };
+
+    let sub = PUBLISHER.subscribe(some_id).unwrap();
+
+    let entry_id = {
+        let interim_event = set_event!(some_id);
+        interim_event.get_entry().get_entry_id()
+    };
+
+    let event = sub
+        .get_receiver()
+        .recv_timeout(Duration::from_millis(100))
+        .unwrap();
+
+    assert_eq!(event.get_entry_id(), entry_id, "Wrong event captured.");
+}
+
+#[test]
+fn default_event_finalized_and_waited() {
+    let some_id = DefaultId { id: 5 };
+
+    let finalized = set_event!(some_id, "wait")
+        .finalize_and_wait(Some(Duration::from_millis(100)))
+        .unwrap();
+
+    assert_eq!(finalized.event_id, some_id, "Wrong event finalized.");
+    assert_eq!(
+        DefaultId::from(set_event!(some_id)),
+        some_id,
+        "Wrong ID converted."
+    );
+}
@@ -0,0 +1,22 @@
+//! This module contains the [`EventEntry`] trait implementation using `#[derive(EventEntry)]`.
+//!
+//! [req:qa.ux.usage]
+
+use evident::event::{entry::EventEntry, origin::Origin};
+
+use super::id::DeriveId;
+
+/// Struct deriving the [`EventEntry`] trait.
+#[derive(Default, Clone, EventEntry)]
+pub struct DeriveEventEntry {
+    #[evident(id)]
+    event_id: DeriveId,
+    #[evident(msg)]
+    msg: Option<String>,
+    #[evident(entry_id)]
+    entry_id: evident::uuid::Uuid,
+    #[evident(origin)]
+    origin: Origin,
+    /// Additional field that is set using `Default`.
+    pub annotations: Vec<String>,
+}
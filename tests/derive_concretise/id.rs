@@ -0,0 +1,20 @@
+//! This module contains the [`Id`](evident::event::Id) trait implementation using `#[derive(Id)]`.
+//!
+//! [req:qa.ux.usage]
+
+/// Struct deriving the [`Id`](evident::event::Id) and [`CaptureControl`](evident::publisher::CaptureControl) traits.
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, Copy, evident::event::Id)]
+#[evident(start = START_CAPTURING, stop = STOP_CAPTURING)]
+pub struct DeriveId {
+    pub id: isize,
+}
+
+/// Event-ID to notify the publisher and all listeners that capturing should be started.
+///
+/// [req:event.id.ctrl], [req:cap.ctrl.start]
+const START_CAPTURING: DeriveId = DeriveId { id: -1 };
+
+/// Event-ID to notify the publisher and all listeners that capturing should be stopped.
+///
+/// [req:event.id.ctrl], [req:cap.ctrl.stop]
+const STOP_CAPTURING: DeriveId = DeriveId { id: -2 };
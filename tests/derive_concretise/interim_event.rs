@@ -0,0 +1,13 @@
+//! This module contains the [`IntermediaryEvent`] trait implementation using `#[derive(IntermediaryEvent)]`.
+//!
+//! [req:qa.ux.usage]
+
+use evident::event::intermediary::IntermediaryEvent;
+
+use super::entry::DeriveEventEntry;
+
+/// Struct deriving the [`IntermediaryEvent`] trait.
+#[derive(IntermediaryEvent)]
+pub struct DeriveInterimEvent {
+    entry: Option<DeriveEventEntry>,
+}
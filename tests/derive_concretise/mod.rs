@@ -0,0 +1,116 @@
+//! This module contains a pub/sub-setup with *evident* using the derive macros of the `derive` feature.
+//!
+//! [req:qa.ux.usage]
+
+use evident::{
+    event::{entry::EventEntry, intermediary::IntermediaryEvent, origin::Origin},
+    publisher::{CaptureControl, CaptureMode, EventTimestampKind},
+    this_origin,
+};
+
+use self::{entry::DeriveEventEntry, id::DeriveId, interim_event::DeriveInterimEvent};
+
+mod entry;
+mod id;
+mod interim_event;
+
+evident::create_static_publisher!(
+    PUBLISHER,
+    id_type = DeriveId,
+    msg_type = String,
+    entry_type = DeriveEventEntry,
+    interm_event_type = DeriveInterimEvent,
+    capture_channel_bound = 1,
+    subscription_channel_bound = 1,
+    capture_mode = CaptureMode::Blocking,
+    timestamp_kind = EventTimestampKind::Created
+);
+
+evident::create_set_event_macro!(
+    no_export,
+    id_type = DeriveId,
+    msg_type = String,
+    entry_type = DeriveEventEntry,
+    interm_event_type = DeriveInterimEvent
+);
+
+/// Test using the derived pub/sub implementation to set and listen to an event.
+///
+/// [req:cap.test.recv]
+#[test]
+fn setup_derived_publisher() {
+    let some_id = DeriveId { id: 3 };
+    let msg = "Some msg";
+
+    let sub = PUBLISHER.subscribe(some_id).unwrap();
+
+    set_event!(some_id, msg).finalize();
+
+    let event = sub
+        .get_receiver()
+        .recv_timeout(std::time::Duration::from_millis(100))
+        .unwrap();
+
+    assert_eq!(
+        event.get_event_id(),
+        &some_id,
+        "Sent and received Ids differ."
+    );
+    assert_eq!(
+        event.get_msg().unwrap(),
+        msg,
+        "Sent and received messages differ."
+    );
+}
+
+#[test]
+fn derived_capture_control() {
+    assert!(
+        DeriveId::start(&DeriveId::start_id()),
+        "Start-ID not detected."
+    );
+    assert!(
+        DeriveId::stop(&DeriveId::stop_id()),
+        "Stop-ID not detected."
+    );
+    assert!(
+        !DeriveId::start(&DeriveId { id: 3 }),
+        "Regular ID detected as start-ID."
+    );
+}
+
+#[test]
+fn derived_entry_keeps_entry_id() {
+    let entry_id = evident::uuid::Uuid::new_v4();
+    let origin: Origin = this_origin!();
+
+    let entry =
+        DeriveEventEntry::with_entry_id(DeriveId { id: 5 }, entry_id, Some("msg"), origin.clone());
+
+    assert_eq!(entry.get_entry_id(), entry_id, "Entry-ID not kept.");
+    assert_eq!(entry.get_origin(), &origin, "Origin not kept.");
+    assert!(
+        entry.annotations.is_empty(),
+        "Unannotated field not set to default."
+    );
+}
+
+#[test]
+fn derived_interim_event_takes_entry() {
+    let mut interim_event: DeriveInterimEvent =
+        IntermediaryEvent::<DeriveId, String, DeriveEventEntry>::new(
+            DeriveId { id: 6 },
+            Some("msg"),
+            this_origin!(),
+        );
+    let entry_id = interim_event.get_entry().get_entry_id();
+
+    let entry = interim_event.take_entry().unwrap();
+
+    assert_eq!(entry.get_entry_id(), entry_id, "Wrong entry taken.");
+    assert_eq!(entry.get_event_id(), &DeriveId { id: 6 }, "Wrong event-ID.");
+    assert!(
+        interim_event.take_entry().is_none(),
+        "Entry taken more than once."
+    );
+}
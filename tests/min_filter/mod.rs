@@ -2,7 +2,7 @@
 //!
 //! [req:qa.ux.usage]
 
-use evident::publisher::{CaptureMode, EventTimestampKind};
+use evident::publisher::{CaptureMode, CaptureOutcome, DispatchError, EventTimestampKind};
 
 use crate::min_filter::id::STOP_CAPTURING;
 
@@ -86,3 +86,29 @@ fn stop_capturing_event_not_filtered() {
         "Stop capturing event was filtered."
     );
 }
+
+#[test]
+fn finalize_and_wait_on_filtered_event() {
+    let filtered_id = MinId { id: 5 };
+    let msg = "Some msg";
+
+    let res = set_event!(filtered_id, msg).finalize_and_wait(None);
+
+    assert_eq!(
+        res.unwrap_err(),
+        DispatchError::NotCaptured,
+        "Filtered event was reported as dispatched."
+    );
+}
+
+#[test]
+fn finalize_checked_on_filtered_event() {
+    let filtered_id = MinId { id: 5 };
+    let msg = "Some msg";
+
+    assert_eq!(
+        set_event!(filtered_id, msg).finalize_checked(),
+        CaptureOutcome::Filtered,
+        "Filtered event was reported as captured."
+    );
+}
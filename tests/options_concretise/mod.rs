@@ -0,0 +1,68 @@
+//! This module contains a pub/sub-setup with *evident* that sets publisher options by name in any order.
+//!
+//! [req:qa.ux.usage]
+
+use evident::publisher::{CaptureMode, EventTimestampKind};
+
+use self::{entry::MinEventEntry, filter::MinFilter, id::MinId, interim_event::MinInterimEvent};
+
+mod entry;
+mod filter;
+mod id;
+mod interim_event;
+mod no_options;
+
+evident::create_static_publisher!(
+    PUBLISHER,
+    id_type = MinId,
+    msg_type = String,
+    entry_type = MinEventEntry,
+    interm_event_type = MinInterimEvent,
+    // Options may be set in any order, and options not set use the defaults of `EvidentPublisherBuilder`.
+    timestamp_kind = EventTimestampKind::Created,
+    filter = MinFilter::default(),
+    thread_name = "options-publisher",
+    filter_type = MinFilter,
+    capture_mode = CaptureMode::Blocking,
+    stack_size = 256 * 1024,
+);
+
+// Note: **no_export** to prevent the macro from adding `#[macro_export]`.
+evident::create_set_event_macro!(
+    no_export,
+    id_type = MinId,
+    msg_type = String,
+    entry_type = MinEventEntry,
+    interm_event_type = MinInterimEvent
+);
+
+#[test]
+fn setup_publisher_with_named_options() {
+    let allowed_id = MinId { id: 2 };
+    let filtered_id = MinId { id: 3 };
+
+    let sub = PUBLISHER
+        .subscribe_to_many(vec![filtered_id, allowed_id])
+        .unwrap();
+
+    // This event is not captured
+    set_event!(filtered_id).finalize();
+
+    // This event is captured
+    set_event!(allowed_id).finalize();
+
+    let event = sub
+        .get_receiver()
+        .recv_timeout(std::time::Duration::from_millis(100))
+        .unwrap();
+
+    assert_eq!(
+        event.get_event_id(),
+        &allowed_id,
+        "Filtered event was captured."
+    );
+    assert!(
+        event.get_timestamp().is_some(),
+        "Timestamp not set on creation."
+    );
+}
@@ -0,0 +1,85 @@
+//! This module contains a pub/sub-setup with *evident* that uses the form with named options, but sets no option.
+//!
+//! [req:qa.ux.usage]
+
+use evident::event::{entry::EventEntry, intermediary::IntermediaryEvent, origin::Origin};
+
+use super::{entry::MinEventEntry, id::MinId};
+
+#[derive(Default, Clone)]
+pub struct NoOptionsEntry(MinEventEntry);
+
+impl EventEntry<MinId, String> for NoOptionsEntry {
+    fn new(event_id: MinId, msg: Option<impl Into<String>>, origin: Origin) -> Self {
+        NoOptionsEntry(MinEventEntry::new(event_id, msg, origin))
+    }
+
+    fn get_event_id(&self) -> &MinId {
+        self.0.get_event_id()
+    }
+
+    fn into_event_id(self) -> MinId {
+        self.0.into_event_id()
+    }
+
+    fn get_entry_id(&self) -> evident::uuid::Uuid {
+        self.0.get_entry_id()
+    }
+
+    fn get_msg(&self) -> Option<&String> {
+        self.0.get_msg()
+    }
+
+    fn get_origin(&self) -> &Origin {
+        self.0.get_origin()
+    }
+}
+
+pub struct NoOptionsInterimEvent {
+    entry: Option<NoOptionsEntry>,
+}
+
+impl IntermediaryEvent<MinId, String, NoOptionsEntry> for NoOptionsInterimEvent {
+    fn new(event_id: MinId, msg: Option<impl Into<String>>, origin: Origin) -> Self {
+        NoOptionsInterimEvent {
+            entry: Some(NoOptionsEntry::new(event_id, msg, origin)),
+        }
+    }
+
+    fn get_entry(&self) -> &NoOptionsEntry {
+        self.entry
+            .as_ref()
+            .expect("Entry of intermediary event was already taken.")
+    }
+
+    fn take_entry(&mut self) -> Option<NoOptionsEntry> {
+        self.entry.take()
+    }
+}
+
+evident::create_static_publisher!(
+    NO_OPTIONS_PUBLISHER,
+    id_type = MinId,
+    msg_type = String,
+    entry_type = NoOptionsEntry,
+    interm_event_type = NoOptionsInterimEvent,
+);
+
+#[test]
+fn setup_publisher_without_named_options() {
+    let id = MinId { id: 3 };
+    let sub = NO_OPTIONS_PUBLISHER.subscribe(id).unwrap();
+
+    NoOptionsInterimEvent::new(id, Some("no options"), evident::this_origin!()).finalize();
+
+    let event = sub
+        .get_receiver()
+        .recv_timeout(std::time::Duration::from_millis(100))
+        .unwrap();
+
+    assert_eq!(event.get_event_id(), &id, "Event not captured.");
+    assert!(
+        event.get_timestamp().is_some(),
+        "Timestamp not set by default."
+    );
+}
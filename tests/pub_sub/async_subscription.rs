@@ -0,0 +1,51 @@
+//! Contains tests for async subscriptions.
+
+use evident::futures::{executor::block_on, StreamExt};
+
+use crate::pub_sub::setup::{id::MinId, TESTS_PUBLISHER};
+
+#[test]
+fn async_subscription_receives_event() {
+    let id = MinId { id: 1 };
+    let msg = "Set message";
+
+    let mut sub = TESTS_PUBLISHER.subscribe_async(id).unwrap();
+
+    set_event!(id, msg).finalize();
+
+    let event = block_on(sub.recv()).unwrap();
+
+    assert_eq!(event.get_event_id(), &id, "Received event has wrong Id.");
+    assert_eq!(
+        event.get_msg().unwrap(),
+        msg,
+        "Received event has wrong msg."
+    );
+}
+
+#[test]
+fn async_subscription_as_stream() {
+    let id_1 = MinId { id: 1 };
+    let id_2 = MinId { id: 2 };
+    let msg = "Set message";
+
+    let sub = TESTS_PUBLISHER
+        .subscribe_to_many_async(vec![id_1, id_2])
+        .unwrap();
+
+    set_event!(id_1, msg).finalize();
+    set_event!(id_2, msg).finalize();
+
+    let events = block_on(sub.take(2).collect::<Vec<_>>());
+
+    assert_eq!(
+        events[0].get_event_id(),
+        &id_1,
+        "First received event has wrong Id."
+    );
+    assert_eq!(
+        events[1].get_event_id(),
+        &id_2,
+        "Second received event has wrong Id."
+    );
+}
@@ -0,0 +1,73 @@
+//! Contains tests for the [`EvidentPublisherBuilder`](evident::publisher::EvidentPublisherBuilder).
+
+use std::{sync::mpsc, time::Duration};
+
+use evident::{
+    event::{entry::EventEntry, Event},
+    publisher::{CaptureMode, EventTimestampKind},
+    this_origin,
+};
+
+use crate::pub_sub::setup::{entry::MinEventEntry, id::MinId, SharedPublisher};
+
+#[test]
+fn builder_uses_defaults() {
+    let publisher = SharedPublisher::builder().build_shared();
+
+    assert_eq!(
+        publisher.get_capture_mode(),
+        CaptureMode::Blocking,
+        "Default capture mode not used."
+    );
+
+    let some_id = MinId { id: 1 };
+    let sub = publisher.subscribe(some_id).unwrap();
+    publisher.inject_event(Event::new(MinEventEntry::new(
+        some_id,
+        None::<String>,
+        this_origin!(),
+    )));
+
+    let event = sub
+        .get_receiver()
+        .recv_timeout(Duration::from_millis(100))
+        .unwrap();
+    assert!(
+        event.get_timestamp().is_some(),
+        "Timestamp not set on capture."
+    );
+}
+
+#[test]
+fn builder_sets_capture_thread_options() {
+    let (name_send, name_recv) = mpsc::channel();
+
+    let publisher = SharedPublisher::builder()
+        .capture_mode(CaptureMode::NonBlocking)
+        .capture_channel_bound(5)
+        .subscription_channel_bound(5)
+        .timestamp_kind(EventTimestampKind::Created)
+        .thread_name("builder-capture")
+        .stack_size(256 * 1024)
+        .build(move |_event| {
+            let _ = name_send.send(std::thread::current().name().map(str::to_string));
+        });
+
+    assert_eq!(
+        publisher.get_capture_mode(),
+        CaptureMode::NonBlocking,
+        "Capture mode not set."
+    );
+
+    publisher.inject_event(Event::new(MinEventEntry::new(
+        MinId { id: 2 },
+        None::<String>,
+        this_origin!(),
+    )));
+
+    assert_eq!(
+        name_recv.recv_timeout(Duration::from_millis(100)).unwrap(),
+        Some("builder-capture".to_string()),
+        "Capture thread name not set."
+    );
+}
@@ -0,0 +1,107 @@
+//! Contains tests for the outcome of capturing events.
+
+use std::{sync::mpsc, time::Duration};
+
+use evident::{
+    event::intermediary::IntermediaryEvent,
+    publisher::{CaptureMode, CaptureOutcome},
+    this_origin,
+};
+
+use crate::pub_sub::setup::{id::MinId, interim_event::SharedInterimEvent, SharedPublisher};
+
+fn try_set_event(publisher: &SharedPublisher, id: MinId) -> CaptureOutcome {
+    let mut interim_event = SharedInterimEvent::new(id, None::<String>, this_origin!());
+    publisher.try_capture(&mut interim_event)
+}
+
+#[test]
+fn outcome_of_captured_and_ignored_events() {
+    let publisher = SharedPublisher::builder().build_shared();
+    let some_id = MinId { id: 1 };
+
+    assert_eq!(
+        try_set_event(&publisher, some_id),
+        CaptureOutcome::Captured,
+        "Event not captured."
+    );
+
+    publisher.disable_id(&some_id);
+    assert_eq!(
+        try_set_event(&publisher, some_id),
+        CaptureOutcome::IdDisabled,
+        "Event of disabled ID captured."
+    );
+    publisher.enable_id(&some_id);
+
+    publisher.stop();
+    assert_eq!(
+        try_set_event(&publisher, some_id),
+        CaptureOutcome::CaptureStopped,
+        "Event captured while capturing is stopped."
+    );
+
+    publisher.shutdown();
+    assert_eq!(
+        try_set_event(&publisher, some_id),
+        CaptureOutcome::PublisherGone,
+        "Event captured after shutdown."
+    );
+}
+
+#[test]
+fn outcome_of_event_dropped_on_full_buffer() {
+    let (started_send, started_recv) = mpsc::channel();
+    let (release_send, release_recv) = mpsc::channel::<()>();
+
+    let publisher = SharedPublisher::builder()
+        .capture_mode(CaptureMode::NonBlocking)
+        .capture_channel_bound(1)
+        .build(move |_event| {
+            let _ = started_send.send(());
+            let _ = release_recv.recv();
+        });
+    let some_id = MinId { id: 1 };
+
+    // Note: The first event blocks the event handler, and the second event fills the capture buffer.
+    assert_eq!(
+        try_set_event(&publisher, some_id),
+        CaptureOutcome::Captured,
+        "First event not captured."
+    );
+    started_recv
+        .recv_timeout(Duration::from_millis(100))
+        .unwrap();
+    assert_eq!(
+        try_set_event(&publisher, some_id),
+        CaptureOutcome::Captured,
+        "Second event not captured."
+    );
+
+    assert_eq!(
+        try_set_event(&publisher, some_id),
+        CaptureOutcome::DroppedFull,
+        "Event captured although the buffer is full."
+    );
+
+    drop(release_send);
+}
+
+#[test]
+fn outcome_of_event_captured_twice() {
+    let publisher = SharedPublisher::builder().build_shared();
+    let mut interim_event =
+        SharedInterimEvent::new(MinId { id: 1 }, None::<String>, this_origin!());
+
+    assert_eq!(
+        publisher.try_capture(&mut interim_event),
+        CaptureOutcome::Captured,
+        "Event not captured."
+    );
+    assert_eq!(
+        publisher.try_capture(&mut interim_event),
+        CaptureOutcome::AlreadyCaptured,
+        "Event captured twice."
+    );
+    assert_eq!(publisher.stats().captured, 1, "Event captured twice.");
+}
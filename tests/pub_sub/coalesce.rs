@@ -0,0 +1,151 @@
+//! Contains tests for coalescing repetitions of events.
+
+use std::time::Duration;
+
+use evident::{
+    event::{entry::EventEntry, Event},
+    this_origin,
+};
+
+use crate::pub_sub::setup::{entry::MinEventEntry, id::MinId, SharedPublisher};
+
+fn inject(publisher: &SharedPublisher, id: MinId, msg: &str) {
+    publisher.inject_event(Event::new(MinEventEntry::new(
+        id,
+        Some(msg.to_string()),
+        this_origin!(),
+    )));
+}
+
+#[test]
+fn repetitions_are_coalesced_into_one_event() {
+    let coalesced_id = MinId { id: 1 };
+    let publisher = SharedPublisher::builder()
+        .coalesce_window(coalesced_id, Duration::from_secs(60))
+        .build_shared();
+    let sub = publisher.subscribe(coalesced_id).unwrap();
+
+    for _ in 0..3 {
+        inject(&publisher, coalesced_id, "repeated");
+    }
+    publisher.flush();
+
+    let event = sub
+        .get_receiver()
+        .recv_timeout(Duration::from_millis(100))
+        .unwrap();
+    assert_eq!(event.get_repetitions(), 3, "Repetitions not coalesced.");
+    assert!(
+        event.get_last_timestamp().unwrap() >= event.get_timestamp().unwrap(),
+        "Last timestamp before first timestamp."
+    );
+    assert!(
+        sub.get_receiver().try_recv().is_err(),
+        "Coalesced repetitions were dispatched."
+    );
+
+    let stats = publisher.stats();
+    assert_eq!(stats.coalesced, 2, "Wrong number of coalesced events.");
+    assert_eq!(stats.dispatched, 1, "Wrong number of dispatched events.");
+    assert_eq!(stats.capture_queue_depth, 0, "Capture queue not empty.");
+}
+
+#[test]
+fn different_message_dispatches_pending_event() {
+    let coalesced_id = MinId { id: 1 };
+    let publisher = SharedPublisher::builder().build_shared();
+    publisher.set_coalesce_window(coalesced_id, Duration::from_secs(60));
+    let sub = publisher.subscribe(coalesced_id).unwrap();
+
+    inject(&publisher, coalesced_id, "first");
+    inject(&publisher, coalesced_id, "first");
+    inject(&publisher, coalesced_id, "second");
+
+    let event = sub
+        .get_receiver()
+        .recv_timeout(Duration::from_millis(100))
+        .unwrap();
+    assert_eq!(
+        event.get_msg().unwrap(),
+        "first",
+        "Pending event not dispatched first."
+    );
+    assert_eq!(event.get_repetitions(), 2, "Wrong number of repetitions.");
+
+    publisher.flush();
+
+    let event = sub
+        .get_receiver()
+        .recv_timeout(Duration::from_millis(100))
+        .unwrap();
+    assert_eq!(event.get_msg().unwrap(), "second", "Wrong message.");
+    assert_eq!(event.get_repetitions(), 1, "Event was coalesced.");
+    assert_eq!(
+        event.get_last_timestamp(),
+        event.get_timestamp(),
+        "Last timestamp differs for single event."
+    );
+}
+
+#[test]
+fn pending_event_dispatched_after_window() {
+    let coalesced_id = MinId { id: 1 };
+    let other_id = MinId { id: 2 };
+    let publisher = SharedPublisher::builder()
+        .coalesce_window(coalesced_id, Duration::from_millis(20))
+        .build_shared();
+    let sub = publisher
+        .subscribe_to_many(vec![coalesced_id, other_id])
+        .unwrap();
+
+    inject(&publisher, coalesced_id, "repeated");
+    inject(&publisher, other_id, "not coalesced");
+
+    let event = sub
+        .get_receiver()
+        .recv_timeout(Duration::from_millis(100))
+        .unwrap();
+    assert_eq!(
+        *event.get_event_id(),
+        other_id,
+        "Event without window was held back."
+    );
+
+    let event = sub
+        .get_receiver()
+        .recv_timeout(Duration::from_millis(500))
+        .unwrap();
+    assert_eq!(
+        *event.get_event_id(),
+        coalesced_id,
+        "Pending event not dispatched after window."
+    );
+    assert_eq!(
+        publisher.remove_coalesce_window(&coalesced_id),
+        Some(Duration::from_millis(20)),
+        "Wrong window removed."
+    );
+}
+
+#[test]
+fn repetitions_do_not_extend_window() {
+    let coalesced_id = MinId { id: 1 };
+    let publisher = SharedPublisher::builder()
+        .coalesce_window(coalesced_id, Duration::from_millis(30))
+        .build_shared();
+    let sub = publisher.subscribe(coalesced_id).unwrap();
+
+    // Repetitions keep arriving for longer than the window.
+    for _ in 0..40 {
+        inject(&publisher, coalesced_id, "repeated");
+        std::thread::sleep(Duration::from_millis(5));
+    }
+    publisher.flush();
+
+    let received = sub.get_receiver().try_iter().count();
+    assert!(
+        received > 1,
+        "Window extended by repetitions, only {} event dispatched.",
+        received
+    );
+}
@@ -0,0 +1,138 @@
+//! Contains tests for the filters and filter combinators of `evident::event::filter`.
+
+use evident::{
+    event::{
+        entry::EventEntry,
+        filter::{AllowIds, DenyIds, Filter, ModulePrefix, MsgPredicate, Or},
+        origin::Origin,
+    },
+    publisher::{CaptureMode, EventTimestampKind, EvidentPublisher},
+};
+
+use crate::pub_sub::setup::{entry::MinEventEntry, id::MinId};
+
+fn entry(id: isize, msg: Option<&str>, module_path: &'static str) -> MinEventEntry {
+    MinEventEntry::new(
+        MinId { id },
+        msg,
+        Origin::new(module_path, file!(), line!()),
+    )
+}
+
+fn allowed(filter: &impl Filter<MinId, String>, entry: &MinEventEntry) -> bool {
+    filter.allow_entry(entry)
+}
+
+#[test]
+fn allow_and_deny_ids() {
+    let allow = AllowIds::new([MinId { id: 1 }, MinId { id: 2 }]);
+    let deny = DenyIds::new([MinId { id: 1 }]);
+
+    assert!(
+        allowed(&allow, &entry(1, None, "a")),
+        "Allowed ID filtered."
+    );
+    assert!(!allowed(&allow, &entry(3, None, "a")), "Other ID allowed.");
+    assert!(!allowed(&deny, &entry(1, None, "a")), "Denied ID allowed.");
+    assert!(allowed(&deny, &entry(3, None, "a")), "Other ID filtered.");
+}
+
+#[test]
+fn module_prefix_matches_path_segments() {
+    let filter = ModulePrefix::new("my_crate::net");
+
+    assert!(
+        allowed(&filter, &entry(1, None, "my_crate::net")),
+        "Module of prefix filtered."
+    );
+    assert!(
+        allowed(&filter, &entry(1, None, "my_crate::net::tcp")),
+        "Submodule of prefix filtered."
+    );
+    assert!(
+        !allowed(&filter, &entry(1, None, "my_crate::network")),
+        "Module sharing a partial segment allowed."
+    );
+    assert!(
+        !allowed(&filter, &entry(1, None, "other_crate")),
+        "Other module allowed."
+    );
+}
+
+#[test]
+fn msg_predicate() {
+    let filter = MsgPredicate::new(|msg: Option<&String>| msg.is_some_and(|m| m.contains("disk")));
+
+    assert!(
+        allowed(&filter, &entry(1, Some("disk full"), "a")),
+        "Matching message filtered."
+    );
+    assert!(
+        !allowed(&filter, &entry(1, Some("cpu hot"), "a")),
+        "Other message allowed."
+    );
+    assert!(
+        !allowed(&filter, &entry(1, None, "a")),
+        "Missing message allowed."
+    );
+}
+
+#[test]
+fn combined_filters() {
+    let filter = AllowIds::<MinId, String>::new([MinId { id: 1 }])
+        .or(ModulePrefix::new("my_crate::net"))
+        .and(DenyIds::new([MinId { id: 2 }]));
+
+    assert!(
+        allowed(&filter, &entry(1, None, "a")),
+        "Allowed ID filtered."
+    );
+    assert!(
+        allowed(&filter, &entry(3, None, "my_crate::net")),
+        "Allowed module filtered."
+    );
+    assert!(
+        !allowed(&filter, &entry(2, None, "my_crate::net")),
+        "Denied ID allowed."
+    );
+    assert!(
+        !allowed(&filter, &entry(3, None, "a")),
+        "Other entry allowed."
+    );
+
+    let inverted = filter.not();
+    assert!(
+        allowed(&inverted, &entry(3, None, "a")),
+        "Inverted filter not applied."
+    );
+}
+
+#[test]
+fn combined_filter_used_by_publisher() {
+    type CombinedFilter =
+        Or<AllowIds<MinId, String>, MsgPredicate<MinId, String, fn(Option<&String>) -> bool>>;
+
+    let filter: CombinedFilter = AllowIds::new([MinId { id: 1 }]).or(MsgPredicate::new(
+        (|msg: Option<&String>| msg.is_some()) as fn(Option<&String>) -> bool,
+    ));
+    let publisher = EvidentPublisher::<MinId, String, MinEventEntry, CombinedFilter>::with_shared(
+        filter,
+        CaptureMode::Blocking,
+        10,
+        10,
+        EventTimestampKind::Captured,
+    );
+
+    assert!(
+        publisher.entry_allowed(&entry(1, None, "a")),
+        "Allowed ID filtered."
+    );
+    assert!(
+        publisher.entry_allowed(&entry(3, Some("msg"), "a")),
+        "Entry with message filtered."
+    );
+    assert!(
+        !publisher.entry_allowed(&entry(3, None, "a")),
+        "Other entry allowed."
+    );
+}
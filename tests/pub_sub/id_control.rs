@@ -0,0 +1,120 @@
+//! Contains tests for disabling and enabling capturing of single event-IDs.
+
+use std::time::Duration;
+
+use evident::{event::intermediary::IntermediaryEvent, publisher::CaptureControl, this_origin};
+
+use crate::pub_sub::setup::{id::MinId, interim_event::SharedInterimEvent, SharedPublisher};
+
+fn set_event(publisher: &SharedPublisher, id: MinId) {
+    let mut interim_event = SharedInterimEvent::new(id, None::<String>, this_origin!());
+    publisher._capture(&mut interim_event);
+}
+
+#[test]
+fn disabled_id_not_captured() {
+    let publisher = SharedPublisher::builder().build_shared();
+    let muted_id = MinId { id: 1 };
+    let other_id = MinId { id: 2 };
+    let sub = publisher
+        .subscribe_to_many(vec![muted_id, other_id])
+        .unwrap();
+
+    assert!(
+        publisher.disable_id(&muted_id),
+        "ID was not enabled before."
+    );
+    assert!(!publisher.disable_id(&muted_id), "ID was disabled twice.");
+    assert!(!publisher.is_id_enabled(&muted_id), "ID not disabled.");
+
+    set_event(&publisher, muted_id);
+    set_event(&publisher, other_id);
+    publisher.flush();
+
+    let event = sub
+        .get_receiver()
+        .recv_timeout(Duration::from_millis(100))
+        .unwrap();
+    assert_eq!(*event.get_event_id(), other_id, "Disabled ID was captured.");
+    assert!(
+        sub.get_receiver().try_recv().is_err(),
+        "Disabled ID was captured."
+    );
+    assert_eq!(
+        publisher.stats().ignored_while_disabled,
+        1,
+        "Wrong number of ignored events."
+    );
+
+    assert!(
+        publisher.enable_id(&muted_id),
+        "ID was not disabled before."
+    );
+    set_event(&publisher, muted_id);
+
+    let event = sub
+        .get_receiver()
+        .recv_timeout(Duration::from_millis(100))
+        .unwrap();
+    assert_eq!(*event.get_event_id(), muted_id, "Enabled ID not captured.");
+}
+
+#[test]
+fn control_events_signal_disabled_ids() {
+    let publisher = SharedPublisher::builder().build_shared();
+    let muted_id = MinId { id: 7 };
+    let disabled_signal = MinId::disabled_signal_id(&muted_id).unwrap();
+    let enabled_signal = MinId::enabled_signal_id(&muted_id).unwrap();
+    let sub = publisher
+        .subscribe_to_many(vec![disabled_signal, enabled_signal])
+        .unwrap();
+
+    // Note: Signals must pass even if the ID of the signal was disabled.
+    publisher.disable_ids([&muted_id, &disabled_signal]);
+    publisher.enable_ids([&muted_id]);
+
+    let event = sub
+        .get_receiver()
+        .recv_timeout(Duration::from_millis(100))
+        .unwrap();
+    assert_eq!(
+        *event.get_event_id(),
+        disabled_signal,
+        "Disabled signal not sent."
+    );
+    assert_eq!(
+        event.get_signaled_id(),
+        Some(&muted_id),
+        "Disabled ID not carried by signal."
+    );
+    let event = sub
+        .get_receiver()
+        .recv_timeout(Duration::from_millis(100))
+        .unwrap();
+    assert_eq!(
+        *event.get_event_id(),
+        enabled_signal,
+        "Enabled signal not sent."
+    );
+    assert_eq!(
+        event.get_signaled_id(),
+        Some(&muted_id),
+        "Enabled ID not carried by signal."
+    );
+}
+
+#[test]
+fn bulk_enable_and_disable() {
+    let publisher = SharedPublisher::builder().build_shared();
+    let ids = [MinId { id: 1 }, MinId { id: 2 }, MinId { id: 3 }];
+
+    assert_eq!(publisher.disable_ids(&ids), 3, "Not all IDs disabled.");
+    assert_eq!(publisher.enable_ids(&ids[..1]), 1, "Wrong ID enabled.");
+
+    let mut disabled = publisher.disabled_ids();
+    disabled.sort_by_key(|id| id.id);
+    assert_eq!(disabled, ids[1..], "Wrong IDs disabled.");
+
+    assert_eq!(publisher.enable_all_ids(), 2, "Not all IDs enabled.");
+    assert!(publisher.disabled_ids().is_empty(), "IDs still disabled.");
+}
@@ -0,0 +1,132 @@
+//! Contains tests for the bridge to the `log` crate.
+
+use evident::{
+    log::{Level, Log, Record},
+    log_bridge::EvidentLogger,
+    publisher::{CaptureMode, EventTimestampKind},
+};
+
+use crate::pub_sub::setup::{id::MinId, SharedPublisher};
+
+const LOG_ID: MinId = MinId { id: 300 };
+
+/// Maps `warn` and `error` records of the `bridge` target to [`LOG_ID`].
+fn map_record(target: &str, level: Level) -> Option<MinId> {
+    (target == "bridge" && level <= Level::Warn).then_some(LOG_ID)
+}
+
+#[test]
+fn log_record_captured_as_event() {
+    let publisher =
+        SharedPublisher::new_shared(CaptureMode::Blocking, 10, 10, EventTimestampKind::Captured);
+    let sub = publisher.subscribe(LOG_ID).unwrap();
+    let logger = EvidentLogger::new_shared(publisher.clone(), map_record);
+
+    logger.log(
+        &Record::builder()
+            .args(format_args!("disk at {}%", 95))
+            .level(Level::Warn)
+            .target("bridge")
+            .module_path_static(Some(module_path!()))
+            .file_static(Some(file!()))
+            .line(Some(42))
+            .build(),
+    );
+
+    let event = sub
+        .get_receiver()
+        .recv_timeout(std::time::Duration::from_millis(100))
+        .unwrap();
+
+    assert_eq!(event.get_event_id(), &LOG_ID, "Wrong event-ID.");
+    assert_eq!(event.get_msg().unwrap(), "disk at 95%", "Wrong message.");
+    assert_eq!(
+        event.get_origin().module_path,
+        module_path!(),
+        "Wrong module path."
+    );
+    assert_eq!(event.get_origin().line_nr, 42, "Wrong line number.");
+}
+
+#[test]
+fn runtime_origin_of_log_record_copied() {
+    let publisher =
+        SharedPublisher::new_shared(CaptureMode::Blocking, 10, 10, EventTimestampKind::Captured);
+    let sub = publisher.subscribe(LOG_ID).unwrap();
+    let logger = EvidentLogger::new_shared(publisher.clone(), map_record);
+
+    let module_path = format!("{}::runtime", module_path!());
+    let filename = String::from("runtime.rs");
+    logger.log(
+        &Record::builder()
+            .args(format_args!("runtime origin"))
+            .level(Level::Error)
+            .target("bridge")
+            .module_path(Some(&module_path))
+            .file(Some(&filename))
+            .line(Some(7))
+            .build(),
+    );
+
+    let event = sub
+        .get_receiver()
+        .recv_timeout(std::time::Duration::from_millis(100))
+        .unwrap();
+
+    assert_eq!(
+        event.get_origin().module_path,
+        module_path,
+        "Runtime module path not copied."
+    );
+    assert_eq!(
+        event.get_origin().filename,
+        filename,
+        "Runtime filename not copied."
+    );
+    assert_eq!(event.get_origin().line_nr, 7, "Wrong line number.");
+}
+
+#[test]
+fn unmapped_log_record_ignored() {
+    let publisher =
+        SharedPublisher::new_shared(CaptureMode::Blocking, 10, 10, EventTimestampKind::Captured);
+    let logger = EvidentLogger::new_shared(publisher.clone(), map_record);
+
+    let info_record = Record::builder()
+        .args(format_args!("info"))
+        .level(Level::Info)
+        .target("bridge")
+        .build();
+
+    assert!(
+        !logger.enabled(info_record.metadata()),
+        "Unmapped level enabled."
+    );
+
+    logger.log(&info_record);
+    publisher.flush();
+
+    assert_eq!(publisher.stats().captured, 0, "Unmapped record captured.");
+}
+
+#[test]
+fn log_record_ignored_while_capturing_stopped() {
+    let publisher =
+        SharedPublisher::new_shared(CaptureMode::Blocking, 10, 10, EventTimestampKind::Captured);
+    let logger = EvidentLogger::new_shared(publisher.clone(), map_record);
+
+    publisher.stop();
+    logger.log(
+        &Record::builder()
+            .args(format_args!("stopped"))
+            .level(Level::Error)
+            .target("bridge")
+            .build(),
+    );
+
+    assert_eq!(
+        publisher.stats().ignored_while_stopped,
+        1,
+        "Record captured while capturing was stopped."
+    );
+}
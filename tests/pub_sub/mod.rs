@@ -1,8 +1,27 @@
 //! Contains specific *evident* tests.
 
 #[macro_use]
-pub(self) mod setup;
+mod setup;
 
+#[cfg(feature = "async")]
+pub mod async_subscription;
+#[cfg(feature = "log")]
+pub mod log_bridge;
+#[cfg(feature = "tracing")]
+pub mod tracing_bridge;
+
+pub mod builder;
+pub mod capture_outcome;
+pub mod coalesce;
+pub mod filter_combinators;
+pub mod id_control;
+pub mod overflow;
+pub mod owned_subscription;
+pub mod rate_limit;
+pub mod replay;
+pub mod retained;
+pub mod runtime_filter;
 pub mod set_events;
+pub mod stats;
 pub mod subscription;
 pub mod threading;
@@ -0,0 +1,148 @@
+//! Contains tests for overflow policies of subscriptions.
+
+use std::time::Duration;
+
+use evident::{
+    event::{entry::EventEntry, Event},
+    subscription::OverflowPolicy,
+    this_origin,
+};
+
+use crate::pub_sub::setup::{entry::MinEventEntry, id::MinId, SharedPublisher, TESTS_PUBLISHER};
+
+/// Channel bound of a subscription to one ID for the test publisher.
+const SINGLE_ID_BOUND: usize = 1 + 500;
+
+#[test]
+fn drop_newest_counts_missed_events() {
+    let id = MinId { id: 100 };
+    let nr_events = SINGLE_ID_BOUND + 9;
+
+    let sub = TESTS_PUBLISHER
+        .subscribe_with_policy(vec![id], OverflowPolicy::DropNewest)
+        .unwrap();
+
+    for i in 0..nr_events {
+        set_event!(id, i.to_string()).finalize();
+    }
+    TESTS_PUBLISHER.flush();
+
+    assert_eq!(sub.get_missed_events(), 9, "Wrong number of missed events.");
+
+    let lagging = TESTS_PUBLISHER.get_lagging_subscriptions();
+    assert!(
+        lagging
+            .iter()
+            .any(|lag| lag.channel_id == sub.get_channel_id() && lag.missed_events == 9),
+        "Subscription not reported as lagging."
+    );
+
+    let first_event = sub.get_receiver().try_recv().unwrap();
+    assert_eq!(
+        first_event.get_msg().unwrap(),
+        "0",
+        "Oldest event was dropped."
+    );
+
+    sub.reset_missed_events();
+    assert_eq!(sub.get_missed_events(), 0, "Missed events not reset.");
+}
+
+#[test]
+fn drop_oldest_keeps_newest_events() {
+    let id = MinId { id: 101 };
+    let nr_events = SINGLE_ID_BOUND + 9;
+
+    let sub = TESTS_PUBLISHER
+        .subscribe_with_policy(vec![id], OverflowPolicy::DropOldest)
+        .unwrap();
+
+    for i in 0..nr_events {
+        set_event!(id, i.to_string()).finalize();
+    }
+    TESTS_PUBLISHER.flush();
+
+    let mut received = Vec::new();
+    while let Ok(event) = sub.get_receiver().recv_timeout(Duration::from_millis(10)) {
+        received.push(event);
+    }
+
+    assert_eq!(
+        sub.get_missed_events(),
+        9,
+        "Wrong number of dropped events."
+    );
+    assert_eq!(
+        received.len() + sub.get_missed_events(),
+        nr_events,
+        "Events got lost without being counted."
+    );
+    assert_eq!(
+        received.first().unwrap().get_msg().unwrap(),
+        "9",
+        "Oldest events were not dropped."
+    );
+    assert_eq!(
+        received.last().unwrap().get_msg().unwrap(),
+        &(nr_events - 1).to_string(),
+        "Newest event was dropped."
+    );
+}
+
+#[test]
+fn block_timeout_drops_event_after_timeout() {
+    let id = MinId { id: 102 };
+    let nr_events = SINGLE_ID_BOUND + 1;
+
+    let sub = TESTS_PUBLISHER
+        .subscribe_with_policy(
+            vec![id],
+            OverflowPolicy::BlockTimeout(Duration::from_millis(5)),
+        )
+        .unwrap();
+
+    for i in 0..nr_events {
+        set_event!(id, i.to_string()).finalize();
+    }
+    TESTS_PUBLISHER.flush();
+
+    assert_eq!(sub.get_missed_events(), 1, "Event was not dropped.");
+}
+
+#[test]
+fn block_timeout_sends_once_space_is_free() {
+    let id = MinId { id: 1 };
+    let nr_events = 20;
+    let publisher = SharedPublisher::builder()
+        .subscription_channel_bound(1)
+        .build_shared();
+
+    let sub = publisher
+        .subscribe_with_policy(
+            vec![id],
+            OverflowPolicy::BlockTimeout(Duration::from_secs(10)),
+        )
+        .unwrap();
+
+    for i in 0..nr_events {
+        publisher.inject_event(Event::new(MinEventEntry::new(
+            id,
+            Some(i.to_string()),
+            this_origin!(),
+        )));
+    }
+
+    // Note: The event handler blocks until received events free space in the subscription channel.
+    for i in 0..nr_events {
+        let event = sub
+            .get_receiver()
+            .recv_timeout(Duration::from_millis(500))
+            .unwrap();
+        assert_eq!(
+            event.get_msg().unwrap(),
+            &i.to_string(),
+            "Events not received in order."
+        );
+    }
+    assert_eq!(sub.get_missed_events(), 0, "Event was dropped.");
+}
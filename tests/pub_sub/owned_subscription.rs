@@ -0,0 +1,53 @@
+//! Contains tests for owned subscriptions of shared publishers.
+
+use std::thread;
+
+use evident::publisher::{CaptureControl, CaptureMode, EventTimestampKind};
+
+use crate::pub_sub::setup::{id::MinId, SharedPublisher};
+
+#[test]
+fn owned_subscription_in_other_thread() {
+    let publisher =
+        SharedPublisher::new_shared(CaptureMode::Blocking, 10, 10, EventTimestampKind::Captured);
+
+    let sub = publisher.subscribe_owned(MinId::stop_id()).unwrap();
+
+    let recv_thread = thread::spawn(move || {
+        sub.get_receiver()
+            .recv_timeout(std::time::Duration::from_millis(100))
+            .unwrap()
+    });
+
+    publisher.stop();
+
+    let event = recv_thread.join().unwrap();
+    assert_eq!(
+        event.get_event_id(),
+        &MinId::stop_id(),
+        "Received event has wrong Id."
+    );
+}
+
+#[test]
+fn modify_owned_subscription() {
+    let publisher =
+        SharedPublisher::new_shared(CaptureMode::Blocking, 10, 10, EventTimestampKind::Captured);
+
+    let mut sub = publisher.subscribe_owned(MinId::stop_id()).unwrap();
+    sub.subscribe_id(MinId::start_id()).unwrap();
+    sub.unsubscribe_id(MinId::stop_id()).unwrap();
+
+    publisher.stop();
+    publisher.start();
+
+    let event = sub
+        .get_receiver()
+        .recv_timeout(std::time::Duration::from_millis(100))
+        .unwrap();
+    assert_eq!(
+        event.get_event_id(),
+        &MinId::start_id(),
+        "Unsubscribed event was received."
+    );
+}
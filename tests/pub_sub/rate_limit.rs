@@ -0,0 +1,174 @@
+//! Contains tests for the rate-limiting and sampling filters of `evident::event::filter`.
+
+use std::time::Duration;
+
+use evident::{
+    event::{
+        entry::EventEntry,
+        filter::{Filter, OriginRateLimit, RateLimit, Sample},
+        origin::Origin,
+    },
+    publisher::{CaptureMode, EventTimestampKind, EvidentPublisher},
+};
+
+use crate::pub_sub::setup::{entry::MinEventEntry, id::MinId};
+
+fn entry(id: isize, line_nr: u32) -> MinEventEntry {
+    MinEventEntry::new(
+        MinId { id },
+        None::<String>,
+        Origin::new(module_path!(), file!(), line_nr),
+    )
+}
+
+fn count_allowed(filter: &impl Filter<MinId, String>, entries: &[MinEventEntry]) -> usize {
+    entries
+        .iter()
+        .filter(|entry| filter.allow_entry(*entry))
+        .count()
+}
+
+#[test]
+fn rate_limit_per_id() {
+    let filter = RateLimit::new(3, Duration::from_secs(3600));
+
+    let hot_entries: Vec<_> = (0..5).map(|line_nr| entry(1, line_nr)).collect();
+    assert_eq!(
+        count_allowed(&filter, &hot_entries),
+        3,
+        "Wrong number of entries allowed."
+    );
+    assert_eq!(
+        filter.suppressed(),
+        2,
+        "Wrong number of suppressed entries."
+    );
+
+    assert!(
+        filter.allow_entry(&entry(2, 0)),
+        "Entry of other ID suppressed."
+    );
+}
+
+#[test]
+fn rate_limit_refills_tokens() {
+    let filter = RateLimit::new(1, Duration::from_millis(50));
+
+    assert!(filter.allow_entry(&entry(1, 0)), "First entry suppressed.");
+    assert!(!filter.allow_entry(&entry(1, 0)), "Second entry allowed.");
+
+    std::thread::sleep(Duration::from_millis(60));
+
+    assert!(
+        filter.allow_entry(&entry(1, 0)),
+        "Entry suppressed after refill."
+    );
+}
+
+#[test]
+fn rate_limit_per_origin() {
+    let filter = OriginRateLimit::new(2, Duration::from_secs(3600));
+
+    let same_origin: Vec<_> = (0..4).map(|id| entry(id, 10)).collect();
+    assert_eq!(
+        count_allowed(&filter, &same_origin),
+        2,
+        "Wrong number of entries allowed."
+    );
+    assert_eq!(
+        filter.suppressed(),
+        2,
+        "Wrong number of suppressed entries."
+    );
+
+    assert!(
+        filter.allow_entry(&entry(1, 11)),
+        "Entry of other origin suppressed."
+    );
+}
+
+#[test]
+fn sample_one_in_n() {
+    let filter = Sample::one_in(3);
+
+    let entries: Vec<_> = (0..9).map(|id| entry(id, 0)).collect();
+    assert_eq!(
+        count_allowed(&filter, &entries),
+        3,
+        "Wrong number of entries allowed."
+    );
+    assert_eq!(
+        filter.suppressed(),
+        6,
+        "Wrong number of suppressed entries."
+    );
+}
+
+#[test]
+fn sample_with_probability() {
+    let entries: Vec<_> = (0..10_000).map(|id| entry(id, 0)).collect();
+
+    assert_eq!(
+        count_allowed(&Sample::probability(0.0), &entries),
+        0,
+        "Entry allowed with probability zero."
+    );
+    assert_eq!(
+        count_allowed(&Sample::probability(1.0), &entries),
+        entries.len(),
+        "Entry suppressed with probability one."
+    );
+
+    let half = Sample::probability(0.5);
+    let allowed = count_allowed(&half, &entries);
+    assert!(
+        (4_000..6_000).contains(&allowed),
+        "Sampled {} of {} entries with probability 0.5.",
+        allowed,
+        entries.len()
+    );
+    assert_eq!(
+        half.suppressed() as usize,
+        entries.len() - allowed,
+        "Wrong number of suppressed entries."
+    );
+}
+
+#[test]
+#[should_panic(expected = "Sample probability must be between 0.0 and 1.0.")]
+fn sample_rejects_nan_probability() {
+    let _ = Sample::<MinId, String>::probability(f64::NAN);
+}
+
+#[test]
+#[should_panic(expected = "Sample probability must be between 0.0 and 1.0.")]
+fn sample_rejects_out_of_range_probability() {
+    let _ = Sample::<MinId, String>::probability(1.5);
+}
+
+#[test]
+fn rate_limit_used_by_publisher() {
+    let publisher =
+        EvidentPublisher::<MinId, String, MinEventEntry, RateLimit<MinId, String>>::with_shared(
+            RateLimit::new(1, Duration::from_secs(3600)),
+            CaptureMode::Blocking,
+            10,
+            10,
+            EventTimestampKind::Captured,
+        );
+
+    assert!(
+        publisher.entry_allowed(&entry(1, 0)),
+        "First entry filtered."
+    );
+    assert!(
+        !publisher.entry_allowed(&entry(1, 0)),
+        "Second entry allowed."
+    );
+
+    assert_eq!(
+        publisher.get_filter().unwrap().suppressed(),
+        1,
+        "Suppressed entries not visible through the publisher."
+    );
+}
@@ -0,0 +1,136 @@
+//! Contains tests for subscriptions that replay retained events.
+
+use std::{sync::mpsc, thread, time::Duration};
+
+use evident::publisher::{CaptureControl, CaptureMode, EventTimestampKind};
+
+use crate::pub_sub::setup::{id::MinId, SharedPublisher};
+
+#[test]
+fn replay_retained_then_live_events() {
+    let publisher =
+        SharedPublisher::new_shared(CaptureMode::Blocking, 10, 10, EventTimestampKind::Captured);
+    publisher.set_replay_capacity(10);
+
+    publisher.stop();
+    publisher.start();
+    publisher.flush();
+
+    let sub = publisher.subscribe_to_all_events_with_replay(10).unwrap();
+
+    publisher.stop();
+    publisher.flush();
+
+    let received_ids: Vec<MinId> = sub
+        .get_receiver()
+        .try_iter()
+        .map(|event| *event.get_event_id())
+        .collect();
+
+    assert_eq!(
+        received_ids,
+        vec![MinId::stop_id(), MinId::start_id(), MinId::stop_id()],
+        "Replayed and live events not received in order."
+    );
+}
+
+#[test]
+fn replay_limited_by_capacity_per_id() {
+    let publisher =
+        SharedPublisher::new_shared(CaptureMode::Blocking, 10, 10, EventTimestampKind::Captured);
+    publisher.set_replay_capacity_per_id(1);
+
+    publisher.stop();
+    publisher.start();
+    publisher.stop();
+    publisher.start();
+    publisher.flush();
+
+    let sub = publisher
+        .subscribe_with_replay(vec![MinId::stop_id()], 5)
+        .unwrap();
+
+    assert_eq!(
+        sub.get_receiver().try_iter().count(),
+        1,
+        "More events replayed than retained per ID."
+    );
+}
+
+#[test]
+fn replay_limited_by_requested_number() {
+    let publisher =
+        SharedPublisher::new_shared(CaptureMode::Blocking, 10, 10, EventTimestampKind::Captured);
+    publisher.set_replay_capacity(10);
+
+    publisher.stop();
+    publisher.start();
+    publisher.flush();
+
+    let sub = publisher.subscribe_to_all_events_with_replay(1).unwrap();
+
+    let received_ids: Vec<MinId> = sub
+        .get_receiver()
+        .try_iter()
+        .map(|event| *event.get_event_id())
+        .collect();
+
+    assert_eq!(
+        received_ids,
+        vec![MinId::start_id()],
+        "Latest retained event not replayed."
+    );
+}
+
+#[test]
+fn no_replay_without_capacity() {
+    let publisher =
+        SharedPublisher::new_shared(CaptureMode::Blocking, 10, 10, EventTimestampKind::Captured);
+
+    publisher.stop();
+    publisher.flush();
+
+    let sub = publisher.subscribe_to_all_events_with_replay(10).unwrap();
+
+    assert!(
+        sub.get_receiver().try_recv().is_err(),
+        "Event replayed without replay capacity."
+    );
+}
+
+#[test]
+fn replay_not_blocked_by_full_subscription() {
+    let publisher =
+        SharedPublisher::new_shared(CaptureMode::Blocking, 10, 1, EventTimestampKind::Captured);
+    publisher.set_replay_capacity(10);
+
+    let full_sub = publisher.subscribe(MinId::stop_id()).unwrap();
+    for _ in 0..5 {
+        publisher.stop();
+    }
+    // Gives the capture thread time to block on the full subscription channel.
+    thread::sleep(Duration::from_millis(50));
+
+    let (done_sender, done_receiver) = mpsc::channel();
+    let replay_publisher = publisher.clone();
+    thread::spawn(move || {
+        let replay_sub = replay_publisher
+            .subscribe_to_all_events_with_replay(10)
+            .unwrap();
+        let _ = done_sender.send((
+            replay_sub.get_receiver().try_iter().count(),
+            replay_publisher.is_retained(&MinId::stop_id()),
+        ));
+    });
+
+    let replayed = done_receiver.recv_timeout(Duration::from_secs(1));
+
+    // Unblocks the capture thread.
+    let received = full_sub.get_receiver().iter().take(5).count();
+
+    assert!(
+        replayed.is_ok(),
+        "Replay subscription blocked by full subscription channel."
+    );
+    assert_eq!(received, 5, "Live events missed.");
+}
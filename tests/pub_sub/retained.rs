@@ -0,0 +1,83 @@
+//! Contains tests for retained event-IDs.
+
+use evident::publisher::{CaptureControl, CaptureMode, EventTimestampKind};
+
+use crate::pub_sub::setup::{id::MinId, SharedPublisher};
+
+#[test]
+fn subscribe_receives_last_retained_event() {
+    let publisher =
+        SharedPublisher::new_shared(CaptureMode::Blocking, 10, 10, EventTimestampKind::Captured);
+    publisher.mark_retained(MinId::stop_id());
+
+    publisher.stop();
+    publisher.start();
+    publisher.stop();
+    publisher.flush();
+
+    let sub = publisher.subscribe(MinId::stop_id()).unwrap();
+
+    assert_eq!(
+        sub.get_receiver().try_iter().count(),
+        1,
+        "Not only the last retained event was received."
+    );
+}
+
+#[test]
+fn last_event_of_retained_id() {
+    let publisher =
+        SharedPublisher::new_shared(CaptureMode::Blocking, 10, 10, EventTimestampKind::Captured);
+
+    assert!(
+        publisher.last_event(&MinId::stop_id()).is_none(),
+        "Last event returned for not retained event-ID."
+    );
+
+    publisher.mark_retained(MinId::stop_id());
+    assert!(
+        publisher.is_retained(&MinId::stop_id()),
+        "Event-ID not marked as retained."
+    );
+    assert!(
+        publisher.last_event(&MinId::stop_id()).is_none(),
+        "Last event returned before an event was captured."
+    );
+
+    publisher.stop();
+    publisher.flush();
+
+    let last_event = publisher.last_event(&MinId::stop_id()).unwrap();
+    assert_eq!(
+        last_event.get_event_id(),
+        &MinId::stop_id(),
+        "Last event has wrong Id."
+    );
+
+    publisher.unmark_retained(&MinId::stop_id());
+    assert!(
+        publisher.last_event(&MinId::stop_id()).is_none(),
+        "Last event kept after retained mark was removed."
+    );
+}
+
+#[test]
+fn retained_event_not_replayed_twice() {
+    let publisher =
+        SharedPublisher::new_shared(CaptureMode::Blocking, 10, 10, EventTimestampKind::Captured);
+    publisher.mark_retained(MinId::stop_id());
+    publisher.set_replay_capacity(10);
+
+    publisher.stop();
+    publisher.flush();
+
+    let sub = publisher
+        .subscribe_with_replay(vec![MinId::stop_id()], 10)
+        .unwrap();
+
+    assert_eq!(
+        sub.get_receiver().try_iter().count(),
+        1,
+        "Retained event received more than once."
+    );
+}
@@ -0,0 +1,119 @@
+//! Contains tests for replacing the filter of a running publisher.
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use evident::{
+    event::{entry::EventEntry, filter::Filter},
+    publisher::{CaptureMode, EventTimestampKind, EvidentPublisher},
+    this_origin,
+};
+
+use crate::pub_sub::setup::{entry::MinEventEntry, id::MinId};
+
+/// Filter that only allows events with an ID greater or equal to the minimum ID.
+struct MinIdFilter {
+    min_id: isize,
+}
+
+impl Filter<MinId, String> for MinIdFilter {
+    fn allow_entry(&self, entry: &impl EventEntry<MinId, String>) -> bool {
+        entry.get_event_id().id >= self.min_id
+    }
+}
+
+type FilteredPublisher = EvidentPublisher<MinId, String, MinEventEntry, MinIdFilter>;
+
+fn entry(id: isize) -> MinEventEntry {
+    MinEventEntry::new(MinId { id }, None::<String>, this_origin!())
+}
+
+#[test]
+fn set_and_clear_filter() {
+    let publisher = FilteredPublisher::with_shared(
+        MinIdFilter { min_id: 10 },
+        CaptureMode::Blocking,
+        10,
+        10,
+        EventTimestampKind::Captured,
+    );
+
+    assert!(
+        !publisher.entry_allowed(&entry(5)),
+        "Initial filter not applied."
+    );
+
+    let initial_filter = publisher.get_filter().unwrap();
+
+    let previous = publisher.set_filter(MinIdFilter { min_id: 1 });
+    assert_eq!(initial_filter.min_id, 10, "Replaced filter changed.");
+    assert_eq!(
+        publisher.get_filter().map(|filter| filter.min_id),
+        Some(1),
+        "New filter not returned."
+    );
+    assert_eq!(
+        previous.map(|filter| filter.min_id),
+        Some(10),
+        "Previous filter not returned."
+    );
+    assert!(
+        publisher.entry_allowed(&entry(5)),
+        "Loosened filter not applied."
+    );
+    assert!(
+        !publisher.entry_allowed(&entry(0)),
+        "Loosened filter not applied."
+    );
+
+    let previous = publisher.clear_filter();
+    assert_eq!(
+        previous.map(|filter| filter.min_id),
+        Some(1),
+        "Previous filter not returned."
+    );
+    assert!(publisher.get_filter().is_none(), "Filter not cleared.");
+    assert!(
+        publisher.entry_allowed(&entry(0)),
+        "Cleared filter applied."
+    );
+}
+
+#[test]
+fn swap_filter_while_capturing() {
+    let publisher =
+        FilteredPublisher::new_shared(CaptureMode::Blocking, 10, 10, EventTimestampKind::Captured);
+    let done = Arc::new(AtomicBool::new(false));
+
+    let checkers: Vec<_> = (0..4)
+        .map(|_| {
+            let publisher = publisher.clone();
+            let done = done.clone();
+            std::thread::spawn(move || {
+                let allowed = entry(100);
+                while !done.load(Ordering::Acquire) {
+                    assert!(
+                        publisher.entry_allowed(&allowed),
+                        "Entry allowed by all filters was filtered."
+                    );
+                }
+            })
+        })
+        .collect();
+
+    for min_id in 0..1000 {
+        publisher.set_filter(MinIdFilter {
+            min_id: min_id % 100,
+        });
+        if min_id % 10 == 0 {
+            publisher.clear_filter();
+        }
+    }
+    done.store(true, Ordering::Release);
+
+    for checker in checkers {
+        checker.join().unwrap();
+    }
+}
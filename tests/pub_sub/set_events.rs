@@ -2,7 +2,11 @@
 
 use std::cmp::Ordering;
 
-use evident::event::origin::Origin;
+use evident::{
+    event::{entry::EventEntry, origin::Origin, Event},
+    publisher::CaptureOutcome,
+    this_origin,
+};
 
 use crate::pub_sub::setup::{
     entry::MinEventEntry, id::MinId, interim_event::MinInterimEvent, TESTS_PUBLISHER,
@@ -322,6 +326,30 @@ fn spawned_set_event_has_different_thread_id() {
     assert_ne!(*event.get_thread_id(), thread_id, "ThreadIds are equal");
 }
 
+#[test]
+fn thread_nr_is_stable_per_thread() {
+    let new_event = || {
+        Event::<MinId, String, MinEventEntry>::new(MinEventEntry::new(
+            MinId { id: 1 },
+            Some(String::new()),
+            this_origin!(),
+        ))
+    };
+
+    let first_nr = new_event().get_thread_nr();
+    let second_nr = new_event().get_thread_nr();
+    let spawned_nr = std::thread::spawn(move || new_event().get_thread_nr())
+        .join()
+        .unwrap();
+
+    assert_ne!(first_nr, 0, "No thread number assigned.");
+    assert_eq!(first_nr, second_nr, "Thread numbers differ in same thread.");
+    assert_ne!(
+        first_nr, spawned_nr,
+        "Thread numbers equal in different threads."
+    );
+}
+
 #[test]
 fn datetime_of_second_event_is_greater() {
     let id = MinId { id: 1 };
@@ -350,3 +378,74 @@ fn datetime_of_second_event_is_greater() {
         "Datetime of second event is not greater than first event."
     );
 }
+
+#[test]
+fn finalize_and_wait_dispatches_event() {
+    let id = MinId { id: 1 };
+    let msg = "Set message";
+
+    let recv = TESTS_PUBLISHER.subscribe(id).unwrap();
+
+    let finalized = set_event!(id, msg)
+        .finalize_and_wait(Some(std::time::Duration::from_millis(100)))
+        .unwrap();
+
+    // Event must already be in the subscription channel, so no waiting is needed.
+    let event = recv.get_receiver().try_recv().unwrap();
+
+    assert_eq!(
+        event.get_entry_id(),
+        *finalized.get_entry_id(),
+        "Received event is not the finalized event."
+    );
+}
+
+#[test]
+fn finalize_checked_captures_event() {
+    let id = MinId { id: 901 };
+    let msg = "Set message";
+
+    let recv = TESTS_PUBLISHER.subscribe(id).unwrap();
+
+    let outcome = set_event!(id, msg).finalize_checked();
+    assert_eq!(outcome, CaptureOutcome::Captured, "Event was not captured.");
+
+    let event = recv
+        .get_receiver()
+        .recv_timeout(std::time::Duration::from_millis(100))
+        .unwrap();
+    assert_eq!(event.get_msg().unwrap(), msg, "Wrong event received.");
+}
+
+#[test]
+fn checked_capture_not_repeated_on_drop() {
+    let id = MinId { id: 902 };
+    let msg = "Set message";
+
+    let recv = TESTS_PUBLISHER.subscribe(id).unwrap();
+    let taken_recv = TESTS_PUBLISHER.subscribe(MinId::default()).unwrap();
+
+    let outcome = set_event!(id, msg).finalize_checked();
+    assert_eq!(outcome, CaptureOutcome::Captured, "Event was not captured.");
+
+    let mut interim_event = set_event!(id, msg);
+    let outcome = TESTS_PUBLISHER.try_capture(&mut interim_event);
+    assert_eq!(outcome, CaptureOutcome::Captured, "Event was not captured.");
+    drop(interim_event);
+
+    for _ in 0..2 {
+        recv.get_receiver()
+            .recv_timeout(std::time::Duration::from_millis(100))
+            .unwrap();
+    }
+    assert!(
+        recv.get_receiver().try_recv().is_err(),
+        "Event was captured more than once."
+    );
+
+    TESTS_PUBLISHER.flush();
+    assert!(
+        taken_recv.get_receiver().try_recv().is_err(),
+        "Taken entry was captured on drop."
+    );
+}
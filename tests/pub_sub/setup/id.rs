@@ -11,9 +11,24 @@ impl std::fmt::Display for MinId {
     }
 }
 
+/// Every decimal digit is one level in the hierarchy (e.g. `71` is a child of `7`).
+impl evident::event::HierarchicalId for MinId {
+    fn parent(&self) -> Option<Self> {
+        if self.id.abs() < 10 {
+            None
+        } else {
+            Some(MinId { id: self.id / 10 })
+        }
+    }
+}
+
 const START_CAPTURING: MinId = MinId { id: -1 };
 const STOP_CAPTURING: MinId = MinId { id: -2 };
 
+/// Offsets of IDs signaling that another ID was disabled or enabled (e.g. `-1_000_007` if ID `7` was disabled).
+const DISABLED_SIGNAL_OFFSET: isize = -1_000_000;
+const ENABLED_SIGNAL_OFFSET: isize = -2_000_000;
+
 impl evident::publisher::CaptureControl for MinId {
     fn start(id: &Self) -> bool {
         id == &START_CAPTURING
@@ -30,4 +45,20 @@ impl evident::publisher::CaptureControl for MinId {
     fn stop_id() -> Self {
         STOP_CAPTURING
     }
+
+    fn disabled_signal_id(id: &Self) -> Option<Self> {
+        Some(MinId {
+            id: DISABLED_SIGNAL_OFFSET - id.id,
+        })
+    }
+
+    fn enabled_signal_id(id: &Self) -> Option<Self> {
+        Some(MinId {
+            id: ENABLED_SIGNAL_OFFSET - id.id,
+        })
+    }
+
+    fn is_signal_id(id: &Self) -> bool {
+        id.id <= DISABLED_SIGNAL_OFFSET + 1
+    }
 }
@@ -3,21 +3,46 @@ use evident::event::{entry::EventEntry, intermediary::IntermediaryEvent, origin:
 use super::{entry::MinEventEntry, id::MinId};
 
 pub struct MinInterimEvent {
-    entry: MinEventEntry,
+    entry: Option<MinEventEntry>,
 }
 
 impl IntermediaryEvent<MinId, String, MinEventEntry> for MinInterimEvent {
     fn new(event_id: MinId, msg: Option<impl Into<String>>, origin: Origin) -> Self {
         MinInterimEvent {
-            entry: MinEventEntry::new(event_id, msg, origin),
+            entry: Some(MinEventEntry::new(event_id, msg, origin)),
         }
     }
 
     fn get_entry(&self) -> &MinEventEntry {
-        &self.entry
+        self.entry
+            .as_ref()
+            .expect("Entry of intermediary event was already taken.")
     }
 
-    fn take_entry(&mut self) -> MinEventEntry {
-        std::mem::take(&mut self.entry)
+    fn take_entry(&mut self) -> Option<MinEventEntry> {
+        self.entry.take()
+    }
+}
+
+/// Intermediary event for shared publishers that must be captured explicitly, because it is not captured on `drop()`.
+pub struct SharedInterimEvent {
+    entry: Option<MinEventEntry>,
+}
+
+impl IntermediaryEvent<MinId, String, MinEventEntry> for SharedInterimEvent {
+    fn new(event_id: MinId, msg: Option<impl Into<String>>, origin: Origin) -> Self {
+        SharedInterimEvent {
+            entry: Some(MinEventEntry::new(event_id, msg, origin)),
+        }
+    }
+
+    fn get_entry(&self) -> &MinEventEntry {
+        self.entry
+            .as_ref()
+            .expect("Entry of intermediary event was already taken.")
+    }
+
+    fn take_entry(&mut self) -> Option<MinEventEntry> {
+        self.entry.take()
     }
 }
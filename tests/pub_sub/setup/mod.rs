@@ -1,4 +1,4 @@
-use evident::publisher::{CaptureMode, EventTimestampKind};
+use evident::publisher::{CaptureMode, EventTimestampKind, EvidentPublisher};
 
 use self::{entry::MinEventEntry, id::MinId, interim_event::MinInterimEvent};
 
@@ -18,6 +18,14 @@ evident::create_static_publisher!(
     timestamp_kind = EventTimestampKind::Captured
 );
 
+/// Publisher type used to create shared publishers at runtime.
+pub type SharedPublisher = EvidentPublisher<
+    MinId,
+    String,
+    MinEventEntry,
+    evident::event::filter::DummyFilter<MinId, String>,
+>;
+
 evident::create_set_event_macro!(
     no_export,
     id_type = crate::pub_sub::setup::id::MinId,
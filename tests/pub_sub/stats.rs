@@ -0,0 +1,50 @@
+//! Contains tests for publisher statistics.
+
+use evident::publisher::{CaptureControl, CaptureMode, EventTimestampKind};
+
+use crate::pub_sub::setup::{id::MinId, SharedPublisher};
+
+#[test]
+fn stats_of_control_events() {
+    let publisher =
+        SharedPublisher::new_shared(CaptureMode::Blocking, 10, 5, EventTimestampKind::Captured);
+
+    let _stop_sub = publisher.subscribe_owned(MinId::stop_id()).unwrap();
+    let _all_sub = publisher.subscribe_to_all_events_owned().unwrap();
+
+    publisher.stop();
+    publisher.start();
+    publisher.flush();
+
+    let stats = publisher.stats();
+
+    assert_eq!(stats.captured, 2, "Wrong number of captured events.");
+    assert_eq!(stats.dispatched, 2, "Wrong number of dispatched events.");
+    assert_eq!(stats.filtered, 0, "Control events were filtered.");
+    assert_eq!(stats.dropped, 0, "Control events were dropped.");
+    assert_eq!(
+        stats.captures_per_id.get(&MinId::stop_id()),
+        Some(&1),
+        "Wrong number of stop events."
+    );
+    assert_eq!(
+        stats.captures_per_id.get(&MinId::start_id()),
+        Some(&1),
+        "Wrong number of start events."
+    );
+    assert_eq!(
+        stats.subscriptions_per_id.get(&MinId::stop_id()),
+        Some(&1),
+        "Wrong number of stop subscriptions."
+    );
+    assert_eq!(
+        stats.all_events_subscriptions, 1,
+        "Wrong number of all events subscriptions."
+    );
+    assert_eq!(stats.capture_queue_depth, 0, "Capture queue not empty.");
+    assert_eq!(stats.capture_channel_bound, 10, "Wrong capture bound.");
+    assert_eq!(
+        stats.subscription_channel_bound, 5,
+        "Wrong subscription bound."
+    );
+}
@@ -300,3 +300,125 @@ fn receiver_for_all_events_tries_subscribe_id() {
         "All events subscription allowed subscription to Id."
     );
 }
+
+#[test]
+fn prefix_subscription_receives_descendant_events() {
+    let prefix_id = MinId { id: 70 };
+    let child_id = MinId { id: 701 };
+    let grandchild_id = MinId { id: 7012 };
+    let sibling_id = MinId { id: 71 };
+    let msg = "Set message";
+
+    let recv = TESTS_PUBLISHER.subscribe_prefix(prefix_id).unwrap();
+
+    set_event!(sibling_id, msg).finalize();
+    set_event!(child_id, msg).finalize();
+    set_event!(grandchild_id, msg).finalize();
+    set_event!(prefix_id, msg).finalize();
+
+    let event_1 = recv
+        .get_receiver()
+        .recv_timeout(std::time::Duration::from_millis(10))
+        .unwrap();
+    assert_eq!(
+        event_1.get_event_id(),
+        &child_id,
+        "Child event not received."
+    );
+
+    let event_2 = recv
+        .get_receiver()
+        .recv_timeout(std::time::Duration::from_millis(10))
+        .unwrap();
+    assert_eq!(
+        event_2.get_event_id(),
+        &grandchild_id,
+        "Grandchild event not received."
+    );
+
+    let event_3 = recv
+        .get_receiver()
+        .recv_timeout(std::time::Duration::from_millis(10))
+        .unwrap();
+    assert_eq!(
+        event_3.get_event_id(),
+        &prefix_id,
+        "Prefix event not received."
+    );
+}
+
+#[test]
+fn prefix_subscription_to_ancestors_receives_event_once() {
+    let prefix_id = MinId { id: 80 };
+    let child_id = MinId { id: 801 };
+    let msg = "Set message";
+
+    let recv = TESTS_PUBLISHER
+        .subscribe_to_many_prefixes(vec![prefix_id, child_id])
+        .unwrap();
+
+    set_event!(child_id, msg).finalize();
+
+    let event = recv
+        .get_receiver()
+        .recv_timeout(std::time::Duration::from_millis(10))
+        .unwrap();
+    assert_eq!(event.get_event_id(), &child_id, "Child event not received.");
+
+    assert!(
+        recv.get_receiver()
+            .recv_timeout(std::time::Duration::from_millis(10))
+            .is_err(),
+        "Event received twice."
+    );
+}
+
+#[test]
+fn subscription_with_predicate_receives_matching_events() {
+    let id = MinId { id: 1 };
+    let ignored_msg = "Ignored message";
+    let matching_msg = "Matching message";
+
+    let recv = TESTS_PUBLISHER
+        .subscribe_where(vec![id], move |event| {
+            event.get_msg().map(|msg| msg.as_str()) == Some(matching_msg)
+        })
+        .unwrap();
+
+    set_event!(id, ignored_msg).finalize();
+    set_event!(id, matching_msg).finalize();
+
+    let event = recv
+        .get_receiver()
+        .recv_timeout(std::time::Duration::from_millis(10))
+        .unwrap();
+    assert_eq!(
+        event.get_msg().unwrap(),
+        matching_msg,
+        "Event not matching the predicate was received."
+    );
+}
+
+#[test]
+fn all_events_subscription_with_predicate() {
+    let ignored_id = MinId { id: 1 };
+    let matching_id = MinId { id: 90 };
+    let msg = "Set message";
+
+    let recv = TESTS_PUBLISHER
+        .subscribe_to_all_events_where(move |event| event.get_event_id() == &matching_id)
+        .unwrap();
+
+    set_event!(ignored_id, msg).finalize();
+    set_event!(matching_id, msg).finalize();
+
+    let event = recv
+        .get_receiver()
+        .recv_timeout(std::time::Duration::from_millis(10))
+        .unwrap();
+    assert_eq!(
+        event.get_event_id(),
+        &matching_id,
+        "Event not matching the predicate was received."
+    );
+}
@@ -0,0 +1,130 @@
+//! Contains tests for the bridge to the `tracing` crate.
+
+use std::sync::{Arc, Mutex};
+
+use evident::{
+    event::{entry::EventEntry, Event},
+    publisher::{CaptureMode, EventTimestampKind},
+    this_origin,
+    tracing::{self, Level, Metadata},
+    tracing_bridge::{emit_tracing_event, EvidentLayer, TracingFields, FORWARDED_TARGET},
+    tracing_subscriber::{layer::Context, prelude::*, Layer},
+};
+
+use crate::pub_sub::setup::{entry::MinEventEntry, id::MinId, SharedPublisher};
+
+const TRACING_ID: MinId = MinId { id: 301 };
+
+/// Maps tracing events with an `evident_id` field to the ID given by this field.
+fn map_tracing_event(_metadata: &Metadata<'_>, fields: &TracingFields) -> Option<MinId> {
+    let id = fields.get("evident_id")?.parse().ok()?;
+    Some(MinId { id })
+}
+
+/// Target, level, and fields of a recorded tracing event.
+type RecordedEvent = (&'static str, Level, TracingFields);
+
+/// Layer that records the target, level, and fields of all tracing events.
+#[derive(Default, Clone)]
+struct CollectingLayer {
+    events: Arc<Mutex<Vec<RecordedEvent>>>,
+}
+
+impl<S: tracing::Subscriber> Layer<S> for CollectingLayer {
+    fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+        let mut fields = TracingFields::default();
+        event.record(&mut fields);
+
+        let metadata = event.metadata();
+        self.events
+            .lock()
+            .unwrap()
+            .push((metadata.target(), *metadata.level(), fields));
+    }
+}
+
+#[test]
+fn tracing_event_captured_as_event() {
+    let publisher =
+        SharedPublisher::new_shared(CaptureMode::Blocking, 10, 10, EventTimestampKind::Captured);
+    let sub = publisher.subscribe(TRACING_ID).unwrap();
+    let layer = EvidentLayer::new_shared(publisher.clone(), map_tracing_event);
+
+    let line_nr = line!() + 2;
+    tracing::subscriber::with_default(tracing_subscriber::registry().with(layer), || {
+        tracing::warn!(evident_id = 301, "disk at {}%", 95);
+    });
+
+    let event = sub
+        .get_receiver()
+        .recv_timeout(std::time::Duration::from_millis(100))
+        .unwrap();
+
+    assert_eq!(event.get_event_id(), &TRACING_ID, "Wrong event-ID.");
+    assert_eq!(event.get_msg().unwrap(), "disk at 95%", "Wrong message.");
+    assert_eq!(
+        event.get_origin().module_path,
+        module_path!(),
+        "Wrong module path."
+    );
+    assert_eq!(event.get_origin().line_nr, line_nr, "Wrong line number.");
+}
+
+#[test]
+fn unmapped_and_forwarded_tracing_events_ignored() {
+    let publisher =
+        SharedPublisher::new_shared(CaptureMode::Blocking, 10, 10, EventTimestampKind::Captured);
+    let layer = EvidentLayer::new_shared(publisher.clone(), map_tracing_event);
+
+    tracing::subscriber::with_default(tracing_subscriber::registry().with(layer), || {
+        tracing::info!("no ID");
+        tracing::info!(target: FORWARDED_TARGET, evident_id = 301, "forwarded");
+    });
+    publisher.flush();
+
+    assert_eq!(
+        publisher.stats().captured,
+        0,
+        "Unmapped or forwarded tracing event captured."
+    );
+}
+
+#[test]
+fn event_emitted_as_tracing_event() {
+    let publisher =
+        SharedPublisher::new_shared(CaptureMode::Blocking, 10, 10, EventTimestampKind::Captured);
+    let sub = publisher.subscribe(TRACING_ID).unwrap();
+    let layer = CollectingLayer::default();
+    let events = layer.events.clone();
+
+    publisher.inject_event(Event::new(MinEventEntry::new(
+        TRACING_ID,
+        Some("forward me".to_string()),
+        this_origin!(),
+    )));
+    let event = sub
+        .get_receiver()
+        .recv_timeout(std::time::Duration::from_millis(100))
+        .unwrap();
+
+    tracing::subscriber::with_default(tracing_subscriber::registry().with(layer), || {
+        emit_tracing_event(&event, Level::ERROR);
+    });
+
+    let events = events.lock().unwrap();
+    assert_eq!(events.len(), 1, "Tracing event not emitted.");
+
+    let (target, level, fields) = &events[0];
+    assert_eq!(*target, FORWARDED_TARGET, "Wrong target.");
+    assert_eq!(*level, Level::ERROR, "Wrong level.");
+    assert_eq!(
+        fields.get("msg"),
+        Some("Some(\"forward me\")"),
+        "Wrong message field."
+    );
+    assert_eq!(
+        fields.get("entry_id"),
+        Some(event.get_entry_id().to_string().as_str()),
+        "Wrong entry-ID field."
+    );
+}
@@ -3,6 +3,9 @@
 //!
 //! [req:qa.ux.usage]
 
+// Note: `crate` in the generated `set_event!()` macro is meant to reference this test crate.
+#![allow(clippy::crate_in_macro_def)]
+
 use evident::publisher::{CaptureMode, EventTimestampKind};
 
 use crate::public_concretise::{entry::MinEventEntry, id::MinId, interim_event::MinInterimEvent};
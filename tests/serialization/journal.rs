@@ -0,0 +1,279 @@
+//! Contains tests for the append-only event journal.
+
+use std::{
+    io::Write,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use evident::{
+    event::{entry::EventEntry, filter::DummyFilter, record::EventRecord, Event},
+    journal::{
+        JournalError, JournalReader, JournalSink, JournalWriter, JOURNAL_MAGIC, JOURNAL_VERSION,
+        MAX_RECORD_LEN,
+    },
+    publisher::{CaptureMode, EventTimestampKind, EvidentPublisher},
+    this_origin,
+};
+
+use super::{entry::MinEventEntry, id::MinId};
+
+type SharedPublisher = EvidentPublisher<MinId, String, MinEventEntry, DummyFilter<MinId, String>>;
+
+/// Returns a path to a not yet existing journal in the temporary directory.
+fn journal_path() -> PathBuf {
+    std::env::temp_dir().join(format!("evident-{}.journal", evident::uuid::Uuid::new_v4()))
+}
+
+fn new_event(id: isize, msg: &str) -> Event<MinId, String, MinEventEntry> {
+    Event::new(MinEventEntry::new(MinId { id }, Some(msg), this_origin!()))
+}
+
+#[test]
+fn write_and_read_journal() {
+    let path = journal_path();
+    let publisher =
+        SharedPublisher::new_shared(CaptureMode::Blocking, 10, 10, EventTimestampKind::Captured);
+
+    let sink = JournalSink::spawn_shared(&publisher, &path).unwrap();
+
+    let first_event = new_event(1, "first");
+    let first_entry_id = first_event.get_entry_id();
+    publisher.inject_event(first_event);
+    publisher.inject_event(new_event(2, "second"));
+    publisher.flush();
+
+    assert_eq!(sink.stop().unwrap(), 2, "Wrong number of written records.");
+
+    let records: Vec<_> = JournalReader::<MinId, String>::open(&path)
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(records.len(), 2, "Wrong number of read records.");
+    assert_eq!(records[0].event_id, MinId { id: 1 }, "Wrong record order.");
+    assert_eq!(
+        records[0].entry_id, first_entry_id,
+        "Entry-ID not kept in record."
+    );
+    assert_eq!(
+        records[1].msg.as_deref(),
+        Some("second"),
+        "Wrong message in record."
+    );
+}
+
+#[test]
+fn inject_journal_into_publisher() {
+    let path = journal_path();
+    let journal_publisher =
+        SharedPublisher::new_shared(CaptureMode::Blocking, 10, 10, EventTimestampKind::Captured);
+
+    let sink = JournalSink::spawn_shared(&journal_publisher, &path).unwrap();
+    let event = new_event(3, "journaled");
+    let entry_id = event.get_entry_id();
+    journal_publisher.inject_event(event);
+    journal_publisher.flush();
+    sink.stop().unwrap();
+
+    let publisher =
+        SharedPublisher::new_shared(CaptureMode::Blocking, 10, 10, EventTimestampKind::Captured);
+    let sub = publisher.subscribe(MinId { id: 3 }).unwrap();
+
+    let injected = JournalReader::<MinId, String>::open(&path)
+        .unwrap()
+        .inject_into(&publisher)
+        .unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(injected, 1, "Wrong number of injected events.");
+
+    let event = sub
+        .get_receiver()
+        .recv_timeout(std::time::Duration::from_millis(100))
+        .unwrap();
+    assert_eq!(event.get_entry_id(), entry_id, "Entry-ID not kept.");
+    assert_eq!(event.get_msg().unwrap(), "journaled", "Message not kept.");
+}
+
+#[test]
+fn reject_file_without_header() {
+    let path = journal_path();
+    std::fs::write(&path, b"no journal").unwrap();
+
+    let res = JournalReader::<MinId, String>::open(&path);
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(
+        matches!(res, Err(JournalError::InvalidHeader)),
+        "File without header accepted."
+    );
+}
+
+#[test]
+fn writer_recovers_torn_header() {
+    let mut header = JOURNAL_MAGIC.to_vec();
+    header.extend_from_slice(&JOURNAL_VERSION.to_le_bytes());
+
+    for torn_len in 1..header.len() {
+        let path = journal_path();
+        std::fs::write(&path, &header[..torn_len]).unwrap();
+
+        let mut writer = JournalWriter::<MinId, String>::open(&path).unwrap();
+        writer
+            .append(&EventRecord::from(&new_event(1, "after torn header")))
+            .unwrap();
+        writer.flush().unwrap();
+        drop(writer);
+
+        let records: Result<Vec<_>, _> = JournalReader::<MinId, String>::open(&path)
+            .unwrap()
+            .collect();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            records.unwrap().len(),
+            1,
+            "Torn header of {} bytes not recovered.",
+            torn_len
+        );
+    }
+}
+
+#[test]
+fn writer_rejects_short_file_without_header() {
+    let path = journal_path();
+    std::fs::write(&path, b"EVIL").unwrap();
+
+    let res = JournalWriter::<MinId, String>::open(&path);
+    let content = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(
+        matches!(res, Err(JournalError::InvalidHeader)),
+        "Short file without header accepted."
+    );
+    assert_eq!(content, b"EVIL", "Short file without header modified.");
+}
+
+#[test]
+fn truncated_record_ends_iteration() {
+    let path = journal_path();
+    let mut file = std::fs::File::create(&path).unwrap();
+    file.write_all(JOURNAL_MAGIC).unwrap();
+    file.write_all(&1u16.to_le_bytes()).unwrap();
+    // Record length without record
+    file.write_all(&10u32.to_le_bytes()).unwrap();
+    drop(file);
+
+    let mut reader = JournalReader::<MinId, String>::open(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(
+        matches!(reader.next(), Some(Err(JournalError::TruncatedRecord))),
+        "Truncated record not detected."
+    );
+    assert!(reader.next().is_none(), "Iteration continued after error.");
+}
+
+#[test]
+fn oversized_record_length_rejected() {
+    let path = journal_path();
+    let mut file = std::fs::File::create(&path).unwrap();
+    file.write_all(JOURNAL_MAGIC).unwrap();
+    file.write_all(&1u16.to_le_bytes()).unwrap();
+    file.write_all(&(MAX_RECORD_LEN + 1).to_le_bytes()).unwrap();
+    drop(file);
+
+    let mut reader = JournalReader::<MinId, String>::open(&path).unwrap();
+    let writer_res = JournalWriter::<MinId, String>::open(&path);
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(
+        matches!(reader.next(), Some(Err(JournalError::RecordTooLarge(_)))),
+        "Oversized record length not rejected by reader."
+    );
+    assert!(
+        matches!(writer_res, Err(JournalError::RecordTooLarge(_))),
+        "Oversized record length not rejected by writer."
+    );
+}
+
+#[test]
+fn writer_truncates_torn_record() {
+    let path = journal_path();
+    let mut writer = JournalWriter::<MinId, String>::open(&path).unwrap();
+    writer
+        .append(&EventRecord::from(&new_event(1, "complete")))
+        .unwrap();
+    writer.flush().unwrap();
+    drop(writer);
+
+    let mut file = std::fs::OpenOptions::new()
+        .append(true)
+        .open(&path)
+        .unwrap();
+    // Record length with only part of the record
+    file.write_all(&10u32.to_le_bytes()).unwrap();
+    file.write_all(b"{\"id").unwrap();
+    drop(file);
+
+    let mut writer = JournalWriter::<MinId, String>::open(&path).unwrap();
+    writer
+        .append(&EventRecord::from(&new_event(2, "appended")))
+        .unwrap();
+    writer.flush().unwrap();
+    drop(writer);
+
+    let records: Result<Vec<_>, _> = JournalReader::<MinId, String>::open(&path)
+        .unwrap()
+        .collect();
+    std::fs::remove_file(&path).unwrap();
+
+    let records = records.unwrap();
+    assert_eq!(records.len(), 2, "Torn record not truncated.");
+    assert_eq!(
+        records[1].msg.as_deref(),
+        Some("appended"),
+        "Wrong record appended after truncation."
+    );
+}
+
+#[test]
+fn sink_stops_while_events_are_received() {
+    let path = journal_path();
+    let publisher =
+        SharedPublisher::new_shared(CaptureMode::Blocking, 10, 10, EventTimestampKind::Captured);
+    let sink = JournalSink::spawn_shared(&publisher, &path).unwrap();
+
+    let injecting = Arc::new(AtomicBool::new(true));
+    let injector = {
+        let publisher = publisher.clone();
+        let injecting = injecting.clone();
+        std::thread::spawn(move || {
+            while injecting.load(Ordering::Acquire) {
+                publisher.inject_event(new_event(1, "load"));
+            }
+        })
+    };
+    std::thread::sleep(Duration::from_millis(20));
+
+    let start = Instant::now();
+    let res = sink.stop();
+    let stop_duration = start.elapsed();
+
+    injecting.store(false, Ordering::Release);
+    injector.join().unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(res.is_ok(), "Journal thread failed.");
+    assert!(
+        stop_duration < Duration::from_secs(1),
+        "Journal thread did not stop while events were received."
+    );
+}
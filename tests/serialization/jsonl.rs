@@ -0,0 +1,105 @@
+//! Contains tests for the JSON Lines writer.
+
+use std::{
+    io::Write,
+    sync::{Arc, Mutex},
+    time::{Duration, UNIX_EPOCH},
+};
+
+use evident::{
+    event::{entry::EventEntry, filter::DummyFilter, Event},
+    jsonl::{rfc3339, JsonLinesConfig, JsonLinesWriter},
+    publisher::{CaptureMode, EventTimestampKind, EvidentPublisher},
+    this_origin,
+};
+
+use super::{entry::MinEventEntry, id::MinId};
+
+type SharedPublisher = EvidentPublisher<MinId, String, MinEventEntry, DummyFilter<MinId, String>>;
+
+/// Output that may be inspected while it is owned by the writer thread.
+#[derive(Clone, Default)]
+struct SharedOutput(Arc<Mutex<Vec<u8>>>);
+
+impl Write for SharedOutput {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn write_events_as_json_lines() {
+    let publisher =
+        SharedPublisher::new_shared(CaptureMode::Blocking, 10, 10, EventTimestampKind::Captured);
+    let output = SharedOutput::default();
+
+    let writer = JsonLinesWriter::spawn_owned(
+        publisher.subscribe_to_all_events_owned().unwrap(),
+        output.clone(),
+        JsonLinesConfig::default(),
+    );
+
+    let event = Event::new(MinEventEntry::new(
+        MinId { id: 1 },
+        Some("json msg"),
+        this_origin!(),
+    ));
+    let entry_id = event.get_entry_id();
+    let thread_nr = event.get_thread_nr();
+    publisher.inject_event(event);
+    publisher.inject_event(Event::new(MinEventEntry::new(
+        MinId { id: 2 },
+        None::<String>,
+        this_origin!(),
+    )));
+    publisher.flush();
+
+    assert_eq!(writer.stop().unwrap(), 2, "Wrong number of written lines.");
+
+    let written = String::from_utf8(output.0.lock().unwrap().clone()).unwrap();
+    let lines: Vec<serde_json::Value> = written
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+
+    assert_eq!(lines.len(), 2, "Wrong number of lines.");
+    assert_eq!(lines[0]["id"]["id"], 1, "Wrong Id written.");
+    assert_eq!(
+        lines[0]["entry_id"],
+        entry_id.to_string(),
+        "Wrong entry-ID written."
+    );
+    assert_eq!(lines[0]["msg"], "json msg", "Wrong message written.");
+    assert_eq!(
+        lines[0]["origin"]["module"],
+        module_path!(),
+        "Wrong origin written."
+    );
+    assert_eq!(
+        lines[0]["thread_nr"], thread_nr,
+        "Wrong thread number written."
+    );
+    assert!(
+        lines[0]["timestamp"].as_str().unwrap().ends_with('Z'),
+        "Timestamp not written in RFC 3339."
+    );
+    assert!(lines[1]["msg"].is_null(), "Missing message not null.");
+}
+
+#[test]
+fn format_timestamp_as_rfc3339() {
+    assert_eq!(
+        rfc3339(UNIX_EPOCH + Duration::from_micros(1_684_311_342_123_456)),
+        "2023-05-17T08:15:42.123456Z",
+        "Wrong timestamp after epoch."
+    );
+    assert_eq!(
+        rfc3339(UNIX_EPOCH - Duration::from_millis(1_500)),
+        "1969-12-31T23:59:58.500000Z",
+        "Wrong timestamp before epoch."
+    );
+}
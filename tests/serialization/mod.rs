@@ -0,0 +1,102 @@
+//! Contains tests for serializing events, origins, and finalized events.
+
+use evident::{
+    event::{finalized::FinalizedEvent, origin::OwnedOrigin, record::EventRecord},
+    publisher::{CaptureMode, EventTimestampKind},
+};
+
+use self::{entry::MinEventEntry, id::MinId, interim_event::MinInterimEvent};
+
+mod entry;
+mod id;
+mod interim_event;
+#[cfg(feature = "journal")]
+mod journal;
+#[cfg(feature = "jsonl")]
+mod jsonl;
+#[cfg(feature = "rotating-file")]
+mod rotating_file;
+
+evident::create_static_publisher!(
+    PUBLISHER,
+    id_type = MinId,
+    msg_type = String,
+    entry_type = MinEventEntry,
+    interm_event_type = MinInterimEvent,
+    capture_channel_bound = 10,
+    subscription_channel_bound = 10,
+    capture_mode = CaptureMode::Blocking,
+    timestamp_kind = EventTimestampKind::Created
+);
+
+evident::create_set_event_macro!(
+    no_export,
+    id_type = MinId,
+    msg_type = String,
+    entry_type = MinEventEntry,
+    interm_event_type = MinInterimEvent
+);
+
+#[test]
+fn serialize_event_as_record() {
+    let id = MinId { id: 1 };
+    let msg = "Serialized message";
+
+    let sub = PUBLISHER.subscribe(id).unwrap();
+
+    set_event!(id, msg).finalize();
+
+    let event = sub
+        .get_receiver()
+        .recv_timeout(std::time::Duration::from_millis(100))
+        .unwrap();
+
+    let serialized = serde_json::to_string(&*event).unwrap();
+    let record: EventRecord<MinId, String> = serde_json::from_str(&serialized).unwrap();
+
+    assert_eq!(
+        record,
+        EventRecord::from(&*event),
+        "Deserialized record differs from event."
+    );
+    assert_eq!(record.event_id, id, "Record has wrong Id.");
+    assert_eq!(
+        record.msg.as_deref(),
+        Some(msg),
+        "Record has wrong message."
+    );
+    assert_eq!(
+        &record.origin,
+        event.get_origin(),
+        "Record has wrong origin."
+    );
+    assert!(record.timestamp.is_some(), "Record has no timestamp.");
+}
+
+#[test]
+fn deserialize_origin_as_owned() {
+    let origin = evident::this_origin!();
+
+    let serialized = serde_json::to_string(&origin).unwrap();
+    let owned: OwnedOrigin = serde_json::from_str(&serialized).unwrap();
+
+    assert_eq!(owned, origin, "Deserialized origin differs.");
+    assert_eq!(
+        owned.to_string(),
+        origin.to_string(),
+        "Origins are displayed differently."
+    );
+}
+
+#[test]
+fn serialize_finalized_event() {
+    let finalized = set_event!(MinId { id: 2 }).finalize();
+
+    let serialized = serde_json::to_string(&finalized).unwrap();
+    let deserialized: FinalizedEvent<MinId> = serde_json::from_str(&serialized).unwrap();
+
+    assert_eq!(
+        deserialized, finalized,
+        "Deserialized finalized event differs."
+    );
+}
@@ -0,0 +1,215 @@
+//! Contains tests for the rotating file sink.
+
+use std::{
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use evident::{
+    event::{entry::EventEntry, filter::DummyFilter, Event},
+    publisher::{CaptureMode, EventTimestampKind, EvidentPublisher},
+    rotating_file::{RotatingFileSink, RotationConfig},
+    this_origin,
+};
+
+use super::{entry::MinEventEntry, id::MinId};
+
+type SharedPublisher = EvidentPublisher<MinId, String, MinEventEntry, DummyFilter<MinId, String>>;
+
+/// Returns a path to a not yet existing directory in the temporary directory.
+fn sink_directory() -> PathBuf {
+    std::env::temp_dir().join(format!("evident-{}", evident::uuid::Uuid::new_v4()))
+}
+
+fn new_event(id: isize) -> Event<MinId, String, MinEventEntry> {
+    Event::new(MinEventEntry::new(
+        MinId { id },
+        Some("rotating"),
+        this_origin!(),
+    ))
+}
+
+fn new_event_with_msg(id: isize, msg: &str) -> Event<MinId, String, MinEventEntry> {
+    Event::new(MinEventEntry::new(MinId { id }, Some(msg), this_origin!()))
+}
+
+#[test]
+fn rotate_by_size_and_keep_max_files() {
+    let directory = sink_directory();
+    let mut config = RotationConfig::new(&directory, "events.jsonl");
+    // Every event exceeds the maximum size
+    config.max_file_size = Some(1);
+    config.max_rotated_files = 2;
+
+    let publisher =
+        SharedPublisher::new_shared(CaptureMode::Blocking, 10, 10, EventTimestampKind::Captured);
+    let sink = RotatingFileSink::spawn_owned(
+        publisher.subscribe_to_all_events_owned().unwrap(),
+        config.clone(),
+    )
+    .unwrap();
+
+    for id in 1..=4 {
+        publisher.inject_event(new_event(id));
+    }
+    publisher.flush();
+
+    assert_eq!(sink.stop(), 0, "Writes were dropped.");
+
+    let newest = std::fs::read_to_string(config.rotated_path(1, false)).unwrap();
+    let oldest_exists = config.rotated_path(2, false).exists();
+    let removed_exists = config.rotated_path(3, false).exists();
+    std::fs::remove_dir_all(&directory).unwrap();
+
+    assert!(
+        newest.contains(r#""id":{"id":4}"#),
+        "Newest rotated file does not contain the last event."
+    );
+    assert!(oldest_exists, "Oldest rotated file missing.");
+    assert!(!removed_exists, "More rotated files kept than configured.");
+}
+
+#[test]
+fn keep_size_if_rotation_fails() {
+    let directory = sink_directory();
+    let mut config = RotationConfig::new(&directory, "events.jsonl");
+    // The third event exceeds the maximum size
+    config.max_file_size = Some(25_000);
+    config.max_rotated_files = 1;
+
+    // Note: A directory at the rotated position lets renaming the current file fail.
+    let blocker = config.rotated_path(1, false);
+    std::fs::create_dir_all(&blocker).unwrap();
+    std::fs::write(blocker.join("blocker"), b"").unwrap();
+
+    let publisher =
+        SharedPublisher::new_shared(CaptureMode::Blocking, 10, 10, EventTimestampKind::Captured);
+    let sink = RotatingFileSink::spawn_owned(
+        publisher.subscribe_to_all_events_owned().unwrap(),
+        config.clone(),
+    )
+    .unwrap();
+
+    let msg = "x".repeat(10_000);
+    for id in 1..=3 {
+        publisher.inject_event(new_event_with_msg(id, &msg));
+    }
+    publisher.flush();
+
+    let start = Instant::now();
+    while std::fs::metadata(config.current_path()).unwrap().len() < 30_000 {
+        assert!(
+            start.elapsed() < Duration::from_secs(1),
+            "Events not written before rotation."
+        );
+        std::thread::sleep(Duration::from_millis(1));
+    }
+
+    std::fs::remove_dir_all(&blocker).unwrap();
+    publisher.inject_event(new_event_with_msg(4, &msg));
+    publisher.flush();
+
+    assert_eq!(sink.stop(), 0, "Writes were dropped.");
+
+    let rotated = std::fs::read_to_string(config.rotated_path(1, false));
+    let current = std::fs::read_to_string(config.current_path()).unwrap();
+    std::fs::remove_dir_all(&directory).unwrap();
+
+    assert_eq!(
+        rotated
+            .expect("File not rotated once possible.")
+            .lines()
+            .count(),
+        4,
+        "Wrong number of events in rotated file."
+    );
+    assert!(current.is_empty(), "Events written after rotation.");
+}
+
+#[test]
+fn gzip_rotated_files() {
+    let directory = sink_directory();
+    let mut config = RotationConfig::new(&directory, "events.jsonl");
+    config.max_file_size = Some(1);
+    config.gzip = true;
+
+    let publisher =
+        SharedPublisher::new_shared(CaptureMode::Blocking, 10, 10, EventTimestampKind::Captured);
+    let sink = RotatingFileSink::spawn_owned(
+        publisher.subscribe_to_all_events_owned().unwrap(),
+        config.clone(),
+    )
+    .unwrap();
+
+    publisher.inject_event(new_event(1));
+    publisher.flush();
+    sink.stop();
+
+    let compressed = std::fs::read(config.rotated_path(1, true)).unwrap();
+    let uncompressed_exists = config.rotated_path(1, false).exists();
+    std::fs::remove_dir_all(&directory).unwrap();
+
+    assert_eq!(
+        compressed[..2],
+        [0x1f, 0x8b],
+        "Rotated file is not gzip compressed."
+    );
+    assert!(!uncompressed_exists, "Uncompressed rotated file kept.");
+}
+
+#[test]
+fn flush_on_stop_event() {
+    let directory = sink_directory();
+    let mut config = RotationConfig::new(&directory, "events.jsonl");
+    config.flush_interval = Duration::from_secs(3600);
+
+    let publisher =
+        SharedPublisher::new_shared(CaptureMode::Blocking, 10, 10, EventTimestampKind::Captured);
+    let sink = RotatingFileSink::spawn_owned(
+        publisher.subscribe_to_all_events_owned().unwrap(),
+        config.clone(),
+    )
+    .unwrap();
+
+    publisher.inject_event(new_event(1));
+    publisher.stop();
+    publisher.flush();
+
+    let mut written = String::new();
+    for _ in 0..100 {
+        written = std::fs::read_to_string(config.current_path()).unwrap();
+        if !written.is_empty() {
+            break;
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+
+    sink.stop();
+    std::fs::remove_dir_all(&directory).unwrap();
+
+    assert_eq!(
+        written.lines().count(),
+        2,
+        "Events not flushed on stop event."
+    );
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn count_dropped_writes_on_full_disk() {
+    // Note: Writing to `/dev/full` always fails as if the disk was full.
+    let mut config = RotationConfig::new("/dev", "full");
+    config.max_file_size = None;
+
+    let publisher =
+        SharedPublisher::new_shared(CaptureMode::Blocking, 10, 10, EventTimestampKind::Captured);
+    let sink =
+        RotatingFileSink::spawn_owned(publisher.subscribe_to_all_events_owned().unwrap(), config)
+            .unwrap();
+
+    publisher.inject_event(new_event(1));
+    publisher.inject_event(new_event(2));
+    publisher.flush();
+
+    assert_eq!(sink.stop(), 2, "Dropped writes not counted.");
+}
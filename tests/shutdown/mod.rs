@@ -0,0 +1,84 @@
+//! Contains tests for flushing and shutting down a publisher.
+//!
+//! **Note:** A separate publisher is used, because shutting down a publisher affects all of its subscriptions.
+
+use std::sync::mpsc::RecvTimeoutError;
+
+use evident::{
+    publisher::{CaptureMode, EventTimestampKind},
+    subscription::SubscriptionError,
+};
+
+use self::{entry::MinEventEntry, id::MinId, interim_event::MinInterimEvent};
+
+mod entry;
+mod id;
+mod interim_event;
+
+evident::create_static_publisher!(
+    PUBLISHER,
+    id_type = MinId,
+    msg_type = String,
+    entry_type = MinEventEntry,
+    interm_event_type = MinInterimEvent,
+    capture_channel_bound = 10,
+    subscription_channel_bound = 10,
+    capture_mode = CaptureMode::Blocking,
+    timestamp_kind = EventTimestampKind::Captured
+);
+
+evident::create_set_event_macro!(
+    no_export,
+    id_type = MinId,
+    msg_type = String,
+    entry_type = MinEventEntry,
+    interm_event_type = MinInterimEvent
+);
+
+#[test]
+fn flush_then_shutdown_publisher() {
+    let id = MinId { id: 1 };
+    let msg = "Set message";
+
+    let sub = PUBLISHER.subscribe(id).unwrap();
+
+    set_event!(id, msg).finalize();
+    PUBLISHER.flush();
+
+    // Event must already be forwarded to the subscription after flushing.
+    let event = sub.get_receiver().try_recv().unwrap();
+    assert_eq!(event.get_event_id(), &id, "Received event has wrong Id.");
+
+    let shutdown_msg = "Shutting down";
+    set_event!(id, shutdown_msg).finalize();
+    PUBLISHER.shutdown();
+
+    assert!(PUBLISHER.is_shut_down(), "Publisher not shut down.");
+    assert!(!PUBLISHER.is_capturing(), "Publisher still capturing.");
+
+    let event = sub.get_receiver().try_recv().unwrap();
+    assert_eq!(
+        event.get_msg().unwrap(),
+        shutdown_msg,
+        "Last event before shutdown was lost."
+    );
+
+    assert_eq!(
+        sub.get_receiver()
+            .recv_timeout(std::time::Duration::from_millis(10))
+            .unwrap_err(),
+        RecvTimeoutError::Disconnected,
+        "Subscription channel not closed on shutdown."
+    );
+
+    assert!(
+        matches!(
+            PUBLISHER.subscribe(id),
+            Err(SubscriptionError::PublisherShutDown)
+        ),
+        "Subscribing to shut down publisher possible."
+    );
+
+    // Must not block on a shut down publisher.
+    PUBLISHER.flush();
+}
@@ -1,5 +1,12 @@
+pub mod default_concretise;
+#[cfg(feature = "derive")]
+pub mod derive_concretise;
 pub mod min_concretise;
 pub mod min_filter;
 pub mod min_msg;
+pub mod options_concretise;
 pub mod pub_sub;
 pub mod public_concretise;
+#[cfg(feature = "serde")]
+pub mod serialization;
+pub mod shutdown;